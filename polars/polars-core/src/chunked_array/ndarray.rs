@@ -1,5 +1,28 @@
 use crate::prelude::*;
+use crate::POOL;
+use ndarray::parallel::prelude::*;
 use ndarray::prelude::*;
+use rayon::prelude::*;
+
+/// Row-major (`C`, numpy's default) vs column-major (`F`, BLAS/Fortran's default)
+/// memory layout for the result of `to_ndarray_par`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NdarrayLayout {
+    C,
+    F,
+}
+
+/// How null values are handled by `to_ndarray_opts`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NullPolicy<N> {
+    /// Error on any null, the same behavior as `to_ndarray`.
+    Error,
+    /// Substitute this value for every null while copying.
+    Fill(N),
+    /// Copy a placeholder (`N::default()`) for null slots and return a companion
+    /// boolean array (`true` = was null) alongside the value array.
+    Mask,
+}
 
 impl<T> ChunkedArray<T>
 where
@@ -12,6 +35,67 @@ where
         let slice = self.cont_slice()?;
         Ok(aview1(slice))
     }
+
+    /// Builds a `ChunkedArray` from a 1D `ndarray::ArrayView1`, the inverse of
+    /// `to_ndarray`. A contiguous view's slice is copied in bulk via
+    /// `new_from_slice`; a strided view falls back to an element-wise copy.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray(name: &str, arr: ArrayView1<T::Native>) -> Self {
+        match arr.as_slice() {
+            Some(slice) => ChunkedArray::new_from_slice(name, slice),
+            None => {
+                let builder = PrimitiveChunkedBuilder::<T>::new(name, arr.len());
+                builder.new_from_iter(arr.iter().copied())
+            }
+        }
+    }
+
+    /// Like `to_ndarray`, but `policy` controls what happens when nulls are present
+    /// instead of always erroring. With `NullPolicy::Mask`, the returned companion
+    /// array marks which slots were null (`true`) so a null-unaware value at that slot
+    /// (a `T::Native::default()` placeholder) can be told apart from real data.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn to_ndarray_opts(
+        &self,
+        policy: NullPolicy<T::Native>,
+    ) -> Result<(Array1<T::Native>, Option<Array1<bool>>)>
+    where
+        T::Native: Default,
+    {
+        if self.null_count() == 0 {
+            return Ok((Array1::from(self.cont_slice()?.to_vec()), None));
+        }
+        if let NullPolicy::Error = policy {
+            return Err(PolarsError::HasNullValues(
+                "Creation of ndarray with null values is not supported.".into(),
+            ));
+        }
+
+        let mut values = Vec::with_capacity(self.len());
+        let mut mask = Vec::with_capacity(self.len());
+        for v in self.into_iter() {
+            match v {
+                Some(x) => {
+                    values.push(x);
+                    mask.push(false);
+                }
+                None => {
+                    match policy {
+                        NullPolicy::Fill(fill) => values.push(fill),
+                        NullPolicy::Mask => values.push(T::Native::default()),
+                        NullPolicy::Error => unreachable!("rejected above"),
+                    }
+                    mask.push(true);
+                }
+            }
+        }
+        let mask = if let NullPolicy::Mask = policy {
+            Some(Array1::from(mask))
+        } else {
+            None
+        };
+        Ok((Array1::from(values), mask))
+    }
 }
 
 impl ListChunked {
@@ -47,9 +131,11 @@ impl ListChunked {
 
                 for series in iter {
                     if series.len() != width {
-                        return Err(PolarsError::ShapeMisMatch(
-                            "Could not create a 2D array. Series have different lengths".into(),
-                        ));
+                        return Err(PolarsError::ShapeMisMatch {
+                            expected: (self.len(), width),
+                            got: (self.len(), series.len()),
+                        }
+                        .context("Could not create a 2D array. Series have different lengths"));
                     }
                     let series = series.cast(&N::get_dtype())?;
                     let ca = series.unpack::<N>()?;
@@ -116,6 +202,182 @@ impl DataFrame {
         }
         Ok(ndarr)
     }
+
+    /// Like `to_ndarray`, but `policy` controls what happens when nulls are present
+    /// instead of always erroring. With `NullPolicy::Mask`, the returned companion
+    /// array marks which cells were null (`true`) so a null-unaware value at that cell
+    /// (an `N::Native::default()` placeholder) can be told apart from real data.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn to_ndarray_opts<N>(
+        &self,
+        policy: NullPolicy<N::Native>,
+    ) -> Result<(Array2<N::Native>, Option<Array2<bool>>)>
+    where
+        N: PolarsNumericType,
+        N::Native: Default,
+    {
+        let shape = self.shape();
+        let mut ndarr = Array2::<N::Native>::from_elem(shape, N::Native::default());
+        let mut mask = if let NullPolicy::Mask = policy {
+            Some(Array2::<bool>::from_elem(shape, false))
+        } else {
+            None
+        };
+
+        for (col_idx, series) in self.get_columns().iter().enumerate() {
+            if series.null_count() != 0 {
+                if let NullPolicy::Error = policy {
+                    return Err(PolarsError::HasNullValues(
+                        "Creation of ndarray with null values is not supported.".into(),
+                    ));
+                }
+            }
+            // this is an Arc clone if already of type N
+            let series = series.cast(&N::get_dtype())?;
+            let ca = series.unpack::<N>()?;
+
+            for (row_idx, v) in ca.into_iter().enumerate() {
+                match v {
+                    Some(x) => ndarr[[row_idx, col_idx]] = x,
+                    None => match policy {
+                        NullPolicy::Fill(fill) => ndarr[[row_idx, col_idx]] = fill,
+                        NullPolicy::Mask => mask.as_mut().unwrap()[[row_idx, col_idx]] = true,
+                        NullPolicy::Error => unreachable!("rejected above"),
+                    },
+                }
+            }
+        }
+        Ok((ndarr, mask))
+    }
+
+    /// Builds a `DataFrame` from a 2D `ndarray::Array2`, the inverse of `to_ndarray`:
+    /// one numeric `Series` per column. `names` supplies column names in order,
+    /// falling back to `"column_<i>"` for any column past the end of (or without) the
+    /// supplied names.
+    ///
+    /// Respects the array's memory layout to avoid a needless copy per column: on an
+    /// F-contiguous (column-major) array each column is itself contiguous, so
+    /// `column.as_slice()` hits and the column is bulk-copied; on a C-contiguous
+    /// (row-major) array a column is strided, so `as_slice()` misses and that column
+    /// falls back to an element-wise copy.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray<N>(arr: Array2<N::Native>, names: Option<Vec<String>>) -> Result<DataFrame>
+    where
+        N: PolarsNumericType,
+    {
+        let (n_rows, n_cols) = arr.dim();
+        let mut series = Vec::with_capacity(n_cols);
+        for col_idx in 0..n_cols {
+            let name = names
+                .as_ref()
+                .and_then(|names| names.get(col_idx).cloned())
+                .unwrap_or_else(|| format!("column_{}", col_idx));
+            let column = arr.column(col_idx);
+            let ca: ChunkedArray<N> = match column.as_slice() {
+                Some(slice) => ChunkedArray::new_from_slice(&name, slice),
+                None => {
+                    let builder = PrimitiveChunkedBuilder::<N>::new(&name, n_rows);
+                    builder.new_from_iter(column.iter().copied())
+                }
+            };
+            series.push(ca.into_series());
+        }
+        DataFrame::new(series)
+    }
+
+    /// Like `to_ndarray`, but fills column-by-column instead of scalar-by-scalar, lets
+    /// the caller choose the output memory layout, and spreads the per-column copies
+    /// across the `POLARS_MAX_THREADS` rayon pool.
+    ///
+    /// When every column is already a single contiguous chunk of type `N` and
+    /// `layout` is `NdarrayLayout::F` (column-major), this skips the per-column assign
+    /// pass in favor of one bulk `extend_from_slice` per column straight into the
+    /// destination buffer: column-major memory *is* "one column's worth of values
+    /// after another", so each column's buffer is copied whole rather than scattered
+    /// cell by cell. The result always owns a copy of the data; it never shares the
+    /// columns' buffers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn to_ndarray_par<N>(&self, layout: NdarrayLayout) -> Result<Array2<N::Native>>
+    where
+        N: PolarsNumericType,
+        N::Native: Send + Sync,
+    {
+        let (n_rows, n_cols) = self.shape();
+
+        // Cast every column up front so a `HasNullValues`/cast error surfaces before
+        // any work is parallelized, and so the pass below only ever touches a
+        // `ChunkedArray<N>`.
+        let columns = self
+            .get_columns()
+            .iter()
+            .map(|series| {
+                if series.null_count() != 0 {
+                    return Err(PolarsError::HasNullValues(
+                        "Creation of ndarray with null values is not supported.".into(),
+                    ));
+                }
+                let series = series.cast(&N::get_dtype())?;
+                Ok(series.unpack::<N>()?.clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if layout == NdarrayLayout::F && columns.iter().all(|ca| ca.cont_slice().is_ok()) {
+            let mut data = Vec::with_capacity(n_rows * n_cols);
+            for ca in &columns {
+                data.extend_from_slice(ca.cont_slice().unwrap());
+            }
+            return Ok(Array2::from_shape_vec((n_rows, n_cols).f(), data)
+                .expect("n_rows * n_cols matches the collected data length"));
+        }
+
+        let mut ndarr = match layout {
+            NdarrayLayout::C => Array2::<N::Native>::zeros((n_rows, n_cols)),
+            NdarrayLayout::F => Array2::<N::Native>::zeros((n_rows, n_cols).f()),
+        };
+
+        let n_threads = n_ndarray_threads();
+        if n_threads > 1 {
+            POOL.install(|| {
+                ndarr
+                    .axis_iter_mut(Axis(1))
+                    .into_par_iter()
+                    .zip(columns.par_iter())
+                    .for_each(|(col, ca)| assign_ndarray_column(col, ca));
+            });
+        } else {
+            ndarr
+                .axis_iter_mut(Axis(1))
+                .zip(columns.iter())
+                .for_each(|(col, ca)| assign_ndarray_column(col, ca));
+        }
+        Ok(ndarr)
+    }
+}
+
+/// Copies a (non-null, already validated above) column into its destination slice.
+/// Uses the `to_ndarray`/`assign_to` zero-copy-view fast path when the column is a
+/// single contiguous chunk, falling back to an element-wise copy for a multi-chunk
+/// column.
+fn assign_ndarray_column<N>(mut col: ArrayViewMut1<N::Native>, ca: &ChunkedArray<N>)
+where
+    N: PolarsNumericType,
+{
+    match ca.to_ndarray() {
+        Ok(view) => view.assign_to(&mut col),
+        Err(_) => {
+            ca.into_no_null_iter()
+                .enumerate()
+                .for_each(|(row_idx, val)| col[row_idx] = val);
+        }
+    }
+}
+
+fn n_ndarray_threads() -> usize {
+    let max = std::env::var("POLARS_MAX_THREADS")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(usize::MAX);
+    std::cmp::min(num_cpus::get(), max)
 }
 
 #[cfg(test)]
@@ -160,4 +422,99 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_chunked_array_from_ndarray() {
+        let arr = array![1.0, 2.0, 3.0];
+        let ca = Float64Chunked::from_ndarray("a", arr.view());
+        assert_eq!(ca.cont_slice().unwrap(), &[1.0, 2.0, 3.0]);
+
+        // a strided (non-contiguous) view still copies correctly
+        let owner = array![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let strided = owner.slice(s![..;2]);
+        let ca = Float64Chunked::from_ndarray("a", strided);
+        assert_eq!(ca.cont_slice().unwrap(), &[1.0, 3.0, 5.0]);
+    }
+
+    #[test]
+    fn test_dataframe_from_ndarray_round_trips_to_ndarray() -> Result<()> {
+        let df = df!["a"=> [1.0, 2.0, 3.0],
+            "b" => [2.0, 3.0, 4.0]
+        ]?;
+        let arr = df.to_ndarray::<Float64Type>()?;
+
+        let round_tripped = DataFrame::from_ndarray::<Float64Type>(
+            arr.clone(),
+            Some(vec!["a".to_string(), "b".to_string()]),
+        )?;
+        assert_eq!(round_tripped.to_ndarray::<Float64Type>()?, arr);
+
+        // without names, columns are labeled "column_0", "column_1", ...
+        let unnamed = DataFrame::from_ndarray::<Float64Type>(arr, None)?;
+        let names: Vec<&str> = unnamed.get_columns().iter().map(|s| s.name()).collect();
+        assert_eq!(names, vec!["column_0", "column_1"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunked_array_to_ndarray_opts() {
+        let ca = Float64Chunked::new("", &[Some(1.0), None, Some(3.0)]);
+
+        assert!(ca.to_ndarray_opts(NullPolicy::Error).is_err());
+
+        let (filled, mask) = ca.to_ndarray_opts(NullPolicy::Fill(0.0)).unwrap();
+        assert_eq!(filled, Array1::from(vec![1.0, 0.0, 3.0]));
+        assert!(mask.is_none());
+
+        let (values, mask) = ca.to_ndarray_opts(NullPolicy::Mask).unwrap();
+        assert_eq!(values, Array1::from(vec![1.0, 0.0, 3.0]));
+        assert_eq!(mask.unwrap(), Array1::from(vec![false, true, false]));
+    }
+
+    #[test]
+    fn test_dataframe_to_ndarray_opts() -> Result<()> {
+        let a = Float64Chunked::new("a", &[Some(1.0), None]).into_series();
+        let b = Float64Chunked::new("b", &[Some(2.0), Some(3.0)]).into_series();
+        let df = DataFrame::new(vec![a, b])?;
+
+        assert!(df.to_ndarray_opts::<Float64Type>(NullPolicy::Error).is_err());
+
+        let (filled, mask) = df.to_ndarray_opts::<Float64Type>(NullPolicy::Fill(-1.0))?;
+        assert_eq!(filled, array![[1.0, 2.0], [-1.0, 3.0]]);
+        assert!(mask.is_none());
+
+        let (values, mask) = df.to_ndarray_opts::<Float64Type>(NullPolicy::Mask)?;
+        assert_eq!(values, array![[1.0, 2.0], [0.0, 3.0]]);
+        assert_eq!(mask.unwrap(), array![[false, false], [true, false]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_ndarray_par_matches_to_ndarray() -> Result<()> {
+        let a = Float64Chunked::new_from_slice("a", &[1.0, 2.0, 3.0]).into_series();
+        let b = Float64Chunked::new_from_slice("b", &[4.0, 5.0, 6.0]).into_series();
+        let df = DataFrame::new(vec![a, b])?;
+
+        // Every column is a single contiguous chunk, so the F-layout call takes the
+        // bulk per-column concatenation path.
+        let f_ordered = df.to_ndarray_par::<Float64Type>(NdarrayLayout::F)?;
+        assert_eq!(f_ordered, df.to_ndarray::<Float64Type>()?);
+        assert!(f_ordered.t().is_standard_layout());
+
+        // The C-layout call falls back to the per-column assign pass.
+        let c_ordered = df.to_ndarray_par::<Float64Type>(NdarrayLayout::C)?;
+        assert_eq!(c_ordered, df.to_ndarray::<Float64Type>()?);
+        assert!(c_ordered.is_standard_layout());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_ndarray_par_rejects_nulls() {
+        let a = Float64Chunked::new("a", &[Some(1.0), None]).into_series();
+        let df = DataFrame::new(vec![a]).unwrap();
+        assert!(df
+            .to_ndarray_par::<Float64Type>(NdarrayLayout::C)
+            .is_err());
+    }
 }
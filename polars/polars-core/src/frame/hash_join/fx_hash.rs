@@ -0,0 +1,89 @@
+//! A small multiply-shift hasher in the style of rustc's `FxHash`, intended as a
+//! drop-in `BuildHasher` for join keys. `ahash::RandomState` is built for
+//! DoS-resistance across untrusted input; within a single join query that property
+//! buys nothing, and its extra mixing rounds cost real throughput on primitive keys
+//! (integers, booleans) that are already well distributed.
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// Rotation-by-5, odd-constant multiply finalizer, the same shape as rustc's
+/// `FxHasher`. Not cryptographically secure and not DoS-resistant -- only suitable
+/// for trusted, in-process keys such as a join's hash table.
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+#[derive(Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    #[inline]
+    fn write_u64(&mut self, w: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ w).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut buf = [0u8; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_ne_bytes(buf));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        FxHasher::write_u64(self, i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// `BuildHasher` for [`FxHasher`]. Usable anywhere a `HashMap<K, V, S: BuildHasher>`
+/// is generic over its hasher, e.g. `HashMap<T, Vec<u32>, FxBuildHasher>`.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::hash::Hash;
+
+    fn hash_with<H: Hasher + Default>(v: impl Hash) -> u64 {
+        let mut hasher = H::default();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn fx_hasher_is_deterministic_and_spreads_small_ints() {
+        let h1 = hash_with::<FxHasher>(1u64);
+        let h2 = hash_with::<FxHasher>(1u64);
+        let h3 = hash_with::<FxHasher>(2u64);
+        assert_eq!(h1, h2);
+        assert_ne!(h1, h3);
+    }
+}
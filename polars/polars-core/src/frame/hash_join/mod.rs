@@ -1,3 +1,4 @@
+mod fx_hash;
 mod multiple_keys;
 
 use crate::frame::hash_join::multiple_keys::{
@@ -44,6 +45,29 @@ pub enum JoinType {
     Left,
     Inner,
     Outer,
+    /// Keep each left row that probes a match in the right table, at most once,
+    /// dropping every right-side column (`WHERE key IN (...)`).
+    Semi,
+    /// Keep each left row that has no match in the right table (`WHERE key NOT IN (...)`).
+    Anti,
+}
+
+/// The partition table index for hash `h` out of `len` tables: the unique `i` in
+/// `0..len` with `(h + i) % len == 0`, i.e. `i = (len - h % len) % len`. This used to
+/// be found by scanning all of `0..len`; computing it directly makes partition
+/// selection O(1) per probe instead of O(len).
+///
+/// NOTE: when `len` is a power of two this still goes through `%`, which the caller
+/// could replace with `& (len - 1)` for a faster bitwise dispatch (`idx = (h >> shift)
+/// & mask`, using high bits for the partition so they stay independent from the low
+/// bits each table's own hasher uses internally). Doing that correctly requires the
+/// build side (`prepare_hashed_relation_threaded` in the `vector_hasher` module) to
+/// shard with the same high/low bit split, and that module isn't part of this tree
+/// snapshot, so the dispatch here is kept equivalent to the original scan rather than
+/// guessing at a build-time scheme it can't see.
+#[inline]
+fn hash_tbl_partition_idx(h: u64, len: u64) -> usize {
+    ((len - h % len) % len) as usize
 }
 
 unsafe fn get_hash_tbl_threaded_join<T, H>(
@@ -51,13 +75,7 @@ unsafe fn get_hash_tbl_threaded_join<T, H>(
     hash_tables: &[HashMap<T, Vec<u32>, H>],
     len: u64,
 ) -> &HashMap<T, Vec<u32>, H> {
-    let mut idx = 0;
-    for i in 0..len {
-        if (h + i) % len == 0 {
-            idx = i as usize;
-        }
-    }
-    hash_tables.get_unchecked(idx)
+    hash_tables.get_unchecked(hash_tbl_partition_idx(h, len))
 }
 
 unsafe fn get_hash_tbl_threaded_join_mut<T, H>(
@@ -65,19 +83,16 @@ unsafe fn get_hash_tbl_threaded_join_mut<T, H>(
     hash_tables: &mut [HashMap<T, Vec<u32>, H>],
     len: u64,
 ) -> &mut HashMap<T, Vec<u32>, H> {
-    let mut idx = 0;
-    for i in 0..len {
-        if (h + i) % len == 0 {
-            idx = i as usize;
-        }
-    }
-    hash_tables.get_unchecked_mut(idx)
+    hash_tables.get_unchecked_mut(hash_tbl_partition_idx(h, len))
 }
 
 /// Probe the build table and add tuples to the results (inner join)
-fn probe_inner<T, F>(
+///
+/// Generic over the hash tables' `BuildHasher` (`S`) so primitive-keyed joins can swap
+/// in a faster hasher than the default `RandomState` -- see the `fx_hash` module.
+fn probe_inner<T, F, S>(
     probe_hashes: &[(u64, T)],
-    hash_tbls: &[HashMap<T, Vec<u32>, RandomState>],
+    hash_tbls: &[HashMap<T, Vec<u32>, S>],
     results: &mut Vec<(u32, u32)>,
     local_offset: usize,
     n_tables: u64,
@@ -172,6 +187,78 @@ where
     })
 }
 
+/// Probe the build table for semi/anti joins. Unlike `probe_inner`, each probing row
+/// contributes at most one entry to `results` (the bare left index, not a tuple) and we
+/// don't walk every build-side duplicate once a match (or its absence) is known.
+fn probe_semi_anti<T, S>(
+    probe_hashes: &[(u64, T)],
+    hash_tbls: &[HashMap<T, Vec<u32>, S>],
+    results: &mut Vec<u32>,
+    local_offset: usize,
+    n_tables: u64,
+    anti: bool,
+) where
+    T: Send + Hash + Eq + Sync + Copy,
+{
+    probe_hashes.iter().enumerate().for_each(|(idx_a, (h, k))| {
+        let idx_a = (idx_a + local_offset) as u32;
+        // probe table that contains the hashed value
+        let current_probe_table = unsafe { get_hash_tbl_threaded_join(*h, hash_tbls, n_tables) };
+
+        let has_match = current_probe_table
+            .raw_entry()
+            .from_key_hashed_nocheck(*h, k)
+            .is_some();
+
+        if has_match != anti {
+            results.push(idx_a);
+        }
+    });
+}
+
+/// Hash join semi/anti. `anti` selects which side of `probe_semi_anti`'s verdict is
+/// kept: `false` keeps left rows with a match (semi), `true` keeps left rows without
+/// one (anti). Always builds the hash table on `b` (the right relation) and probes
+/// with `a` (the left relation) in order, the same fixed roles `hash_join_tuples_left_threaded`
+/// uses, since the result must be left indices in left order rather than a balanced
+/// swap-for-size pair of tuples.
+fn hash_join_tuples_semi_anti_threaded<T, I, J>(a: Vec<I>, b: Vec<J>, anti: bool) -> Vec<u32>
+where
+    I: Iterator<Item = T> + Send,
+    J: Iterator<Item = T> + Send,
+    T: Send + Hash + Eq + Sync + Copy + Debug,
+{
+    let hash_tbls = prepare_hashed_relation_threaded(b);
+    let random_state = hash_tbls[0].hasher().clone();
+    let (probe_hashes, _) = create_hash_and_keys_threaded_vectorized(a, Some(random_state));
+
+    let offsets = probe_hashes
+        .iter()
+        .map(|ph| ph.len())
+        .scan(0, |state, val| {
+            let out = *state;
+            *state += val;
+            Some(out)
+        })
+        .collect::<Vec<_>>();
+
+    let n_tables = hash_tbls.len() as u64;
+
+    POOL.install(|| {
+        probe_hashes
+            .into_par_iter()
+            .zip(offsets)
+            .map(|(probe_hashes, offset)| {
+                let hash_tbls = &hash_tbls;
+                let mut results = Vec::with_capacity(probe_hashes.len());
+                probe_semi_anti(&probe_hashes, hash_tbls, &mut results, offset, n_tables, anti);
+                results
+            })
+            .flatten()
+            .collect()
+    })
+}
+
 fn hash_join_tuples_left_threaded<T, I, J>(a: Vec<I>, b: Vec<J>) -> Vec<(u32, Option<u32>)>
 where
     I: Iterator<Item = T> + Send,
@@ -239,9 +326,9 @@ where
 }
 
 /// Probe the build table and add tuples to the results (inner join)
-fn probe_outer<T, F, G, H>(
+fn probe_outer<T, F, G, D, S>(
     probe_hashes: &[Vec<(u64, T)>],
-    hash_tbls: &mut [HashMap<T, Vec<u32>, RandomState>],
+    hash_tbls: &mut [HashMap<T, Vec<u32>, S>],
     results: &mut Vec<(Option<u32>, Option<u32>)>,
     n_tables: u64,
     // Function that get index_a, index_b when there is a match and pushes to result
@@ -249,7 +336,10 @@ fn probe_outer<T, F, G, H>(
     // Function that get index_a when there is no match and pushes to result
     swap_fn_no_match: G,
     // Function that get index_b from the build table that did not match any in A and pushes to result
-    swap_fn_drain: H,
+    swap_fn_drain: D,
+    // When true, the drained (unmatched build-side) rows are emitted in stable,
+    // input-determined order instead of whatever order the hash maps iterate in.
+    deterministic: bool,
 ) where
     T: Send + Hash + Eq + Sync + Copy,
     // idx_a, idx_b -> ...
@@ -257,7 +347,7 @@ fn probe_outer<T, F, G, H>(
     // idx_a -> ...
     G: Fn(u32) -> (Option<u32>, Option<u32>),
     // idx_b -> ...
-    H: Fn(u32) -> (Option<u32>, Option<u32>),
+    D: Fn(u32) -> (Option<u32>, Option<u32>),
 {
     let mut idx_a = 0;
     for probe_hashes in probe_hashes {
@@ -284,14 +374,42 @@ fn probe_outer<T, F, G, H>(
         }
     }
 
-    for hash_tbl in hash_tbls {
-        hash_tbl.iter().for_each(|(_k, indexes_b)| {
-            // remaining joined values from the right table
-            results.extend(indexes_b.iter().map(|&idx_b| swap_fn_drain(idx_b)))
-        });
+    if deterministic {
+        // `HashMap` iteration order is run-to-run unstable, so the drain phase would
+        // otherwise emit unmatched right rows in a nondeterministic order. Collecting
+        // the leftovers of every table and sorting them once by build-side index makes
+        // the drain come out in the right relation's input order, independent of which
+        // partition table each `idx_b` landed in.
+        let mut remaining: Vec<u32> = hash_tbls
+            .iter()
+            .flat_map(|hash_tbl| {
+                hash_tbl
+                    .iter()
+                    .flat_map(|(_k, indexes_b)| indexes_b.iter().copied())
+            })
+            .collect();
+        remaining.sort_unstable();
+        results.extend(remaining.into_iter().map(&swap_fn_drain));
+    } else {
+        for hash_tbl in hash_tbls {
+            hash_tbl.iter().for_each(|(_k, indexes_b)| {
+                // remaining joined values from the right table
+                results.extend(indexes_b.iter().map(|&idx_b| swap_fn_drain(idx_b)))
+            });
+        }
     }
 }
 
+/// Whether the outer-join drain phase (the unmatched build-side rows) should come out
+/// in a stable, reproducible order rather than whatever order the hash tables iterate
+/// in. Off by default since sorting costs something and most callers don't need
+/// run-to-run-identical output; set `POLARS_JOIN_DETERMINISTIC=1` to opt in.
+fn outer_join_deterministic() -> bool {
+    std::env::var("POLARS_JOIN_DETERMINISTIC")
+        .map(|s| s == "1")
+        .unwrap_or(false)
+}
+
 /// Hash join outer. Both left and right can have no match so Options
 fn hash_join_tuples_outer<T, I, J>(
     a: Vec<I>,
@@ -323,6 +441,7 @@ where
     let (probe_hashes, _) = create_hash_and_keys_threaded_vectorized(a, Some(random_state));
 
     let n_tables = hash_tbls.len() as u64;
+    let deterministic = outer_join_deterministic();
 
     // probe the hash table.
     // Note: indexes from b that are not matched will be None, Some(idx_b)
@@ -338,6 +457,7 @@ where
             |idx_a, idx_b| (Some(idx_b), Some(idx_a)),
             |idx_a| (None, Some(idx_a)),
             |idx_b| (Some(idx_b), None),
+            deterministic,
         )
     } else {
         probe_outer(
@@ -348,6 +468,7 @@ where
             |idx_a, idx_b| (Some(idx_a), Some(idx_b)),
             |idx_a| (Some(idx_a), None),
             |idx_b| (None, Some(idx_b)),
+            deterministic,
         )
     }
     results
@@ -363,6 +484,69 @@ pub(crate) trait HashJoin<T> {
     fn hash_join_outer(&self, _other: &ChunkedArray<T>) -> Vec<(Option<u32>, Option<u32>)> {
         unimplemented!()
     }
+    /// Left-side indices (in left order) whose key has a match in `other`, each
+    /// reported once regardless of how many matches it has.
+    fn hash_join_semi(&self, _other: &ChunkedArray<T>) -> Vec<u32> {
+        unimplemented!()
+    }
+    /// Left-side indices (in left order) whose key has no match in `other`.
+    fn hash_join_anti(&self, _other: &ChunkedArray<T>) -> Vec<u32> {
+        unimplemented!()
+    }
+}
+
+/// Whether two canonicalized NaN keys should join to each other ("SQL-style" `NaN =
+/// NaN`, the dataframe-friendly default) or be treated as non-matching, the same way a
+/// null value never matches anything. Set `POLARS_JOIN_NAN_EQ=0` to opt out.
+fn join_nans_equal() -> bool {
+    std::env::var("POLARS_JOIN_NAN_EQ")
+        .map(|s| s != "0")
+        .unwrap_or(true)
+}
+
+/// Canonicalizes a float into the bit pattern used as its join key: `-0.0` is folded
+/// into `0.0`'s bits so the two compare equal like `==` does, and -- when `join_nans`
+/// is set -- every NaN payload/signaling bit pattern is folded into one canonical NaN
+/// so any two NaNs join to each other instead of only matching an identical payload.
+trait FloatJoinKey: Copy {
+    type Bits: Copy + Eq + Hash + Send + Sync + Debug;
+    fn float_join_key(self, join_nans: bool) -> Self::Bits;
+}
+
+impl FloatJoinKey for f32 {
+    type Bits = u32;
+    #[inline]
+    fn float_join_key(self, join_nans: bool) -> u32 {
+        if self.is_nan() {
+            if join_nans {
+                f32::NAN.to_bits()
+            } else {
+                self.to_bits()
+            }
+        } else if self == 0.0 {
+            0.0f32.to_bits()
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+impl FloatJoinKey for f64 {
+    type Bits = u64;
+    #[inline]
+    fn float_join_key(self, join_nans: bool) -> u64 {
+        if self.is_nan() {
+            if join_nans {
+                f64::NAN.to_bits()
+            } else {
+                self.to_bits()
+            }
+        } else if self == 0.0 {
+            0.0f64.to_bits()
+        } else {
+            self.to_bits()
+        }
+    }
 }
 
 macro_rules! impl_float_hash_join {
@@ -370,6 +554,7 @@ macro_rules! impl_float_hash_join {
         impl HashJoin<$type> for $ca {
             fn hash_join_inner(&self, other: &$ca) -> Vec<(u32, u32)> {
                 let (a, b, swap) = det_hash_prone_order!(self, other);
+                let join_nans = join_nans_equal();
 
                 let n_threads = n_join_threads();
                 let splitted_a = split_ca(a, n_threads).unwrap();
@@ -379,22 +564,28 @@ macro_rules! impl_float_hash_join {
                     (0, 0) => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(move |v| v.float_join_key(join_nans)))
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(move |v| v.float_join_key(join_nans)))
                             .collect_vec();
                         hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
                     }
                     _ => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))
+                            })
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))
+                            })
                             .collect_vec();
                         hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
                     }
@@ -402,6 +593,7 @@ macro_rules! impl_float_hash_join {
             }
             fn hash_join_left(&self, other: &$ca) -> Vec<(u32, Option<u32>)> {
                 let n_threads = n_join_threads();
+                let join_nans = join_nans_equal();
 
                 let a = self;
                 let b = other;
@@ -412,22 +604,28 @@ macro_rules! impl_float_hash_join {
                     (0, 0) => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(move |v| v.float_join_key(join_nans)))
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(move |v| v.float_join_key(join_nans)))
                             .collect_vec();
                         hash_join_tuples_left_threaded(iters_a, iters_b)
                     }
                     _ => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))
+                            })
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))
+                            })
                             .collect_vec();
                         hash_join_tuples_left_threaded(iters_a, iters_b)
                     }
@@ -435,20 +633,67 @@ macro_rules! impl_float_hash_join {
             }
             fn hash_join_outer(&self, other: &$ca) -> Vec<(Option<u32>, Option<u32>)> {
                 let (a, b, swap) = det_hash_prone_order!(self, other);
+                let join_nans = join_nans_equal();
 
                 match (a.null_count() == 0, b.null_count() == 0) {
                     (true, true) => hash_join_tuples_outer(
-                        vec![a.into_no_null_iter().map(|v| v.to_bits())],
-                        vec![b.into_no_null_iter().map(|v| v.to_bits())],
+                        vec![a.into_no_null_iter().map(move |v| v.float_join_key(join_nans))],
+                        vec![b.into_no_null_iter().map(move |v| v.float_join_key(join_nans))],
                         swap,
                     ),
                     _ => hash_join_tuples_outer(
-                        vec![a.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits()))],
-                        vec![b.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits()))],
+                        vec![a
+                            .into_iter()
+                            .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))],
+                        vec![b
+                            .into_iter()
+                            .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))],
                         swap,
                     ),
                 }
             }
+            fn hash_join_semi(&self, other: &$ca) -> Vec<u32> {
+                let a = self;
+                let b = other;
+                let join_nans = join_nans_equal();
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => hash_join_tuples_semi_anti_threaded(
+                        vec![a.into_no_null_iter().map(move |v| v.float_join_key(join_nans))],
+                        vec![b.into_no_null_iter().map(move |v| v.float_join_key(join_nans))],
+                        false,
+                    ),
+                    _ => hash_join_tuples_semi_anti_threaded(
+                        vec![a
+                            .into_iter()
+                            .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))],
+                        vec![b
+                            .into_iter()
+                            .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))],
+                        false,
+                    ),
+                }
+            }
+            fn hash_join_anti(&self, other: &$ca) -> Vec<u32> {
+                let a = self;
+                let b = other;
+                let join_nans = join_nans_equal();
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => hash_join_tuples_semi_anti_threaded(
+                        vec![a.into_no_null_iter().map(move |v| v.float_join_key(join_nans))],
+                        vec![b.into_no_null_iter().map(move |v| v.float_join_key(join_nans))],
+                        true,
+                    ),
+                    _ => hash_join_tuples_semi_anti_threaded(
+                        vec![a
+                            .into_iter()
+                            .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))],
+                        vec![b
+                            .into_iter()
+                            .map(move |opt_v| opt_v.map(|v| v.float_join_key(join_nans)))],
+                        true,
+                    ),
+                }
+            }
         }
     };
 }
@@ -467,6 +712,12 @@ impl HashJoin<CategoricalType> for CategoricalChunked {
     fn hash_join_outer(&self, other: &CategoricalChunked) -> Vec<(Option<u32>, Option<u32>)> {
         self.deref().hash_join_outer(&other.cast().unwrap())
     }
+    fn hash_join_semi(&self, other: &CategoricalChunked) -> Vec<u32> {
+        self.deref().hash_join_semi(&other.cast().unwrap())
+    }
+    fn hash_join_anti(&self, other: &CategoricalChunked) -> Vec<u32> {
+        self.deref().hash_join_anti(&other.cast().unwrap())
+    }
 }
 
 fn n_join_threads() -> usize {
@@ -562,6 +813,62 @@ where
             }
         }
     }
+
+    fn hash_join_semi(&self, other: &ChunkedArray<T>) -> Vec<u32> {
+        let n_threads = n_join_threads();
+
+        let a = self;
+        let b = other;
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        match (a.null_count(), b.null_count()) {
+            (0, 0) => {
+                let iters_a = splitted_a
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                let iters_b = splitted_b
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, false)
+            }
+            _ => {
+                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, false)
+            }
+        }
+    }
+
+    fn hash_join_anti(&self, other: &ChunkedArray<T>) -> Vec<u32> {
+        let n_threads = n_join_threads();
+
+        let a = self;
+        let b = other;
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        match (a.null_count(), b.null_count()) {
+            (0, 0) => {
+                let iters_a = splitted_a
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                let iters_b = splitted_b
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, true)
+            }
+            _ => {
+                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, true)
+            }
+        }
+    }
 }
 
 impl HashJoin<BooleanType> for BooleanChunked {
@@ -646,6 +953,62 @@ impl HashJoin<BooleanType> for BooleanChunked {
             }
         }
     }
+
+    fn hash_join_semi(&self, other: &BooleanChunked) -> Vec<u32> {
+        let n_threads = n_join_threads();
+
+        let a = self;
+        let b = other;
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        match (a.null_count(), b.null_count()) {
+            (0, 0) => {
+                let iters_a = splitted_a
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                let iters_b = splitted_b
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, false)
+            }
+            _ => {
+                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, false)
+            }
+        }
+    }
+
+    fn hash_join_anti(&self, other: &BooleanChunked) -> Vec<u32> {
+        let n_threads = n_join_threads();
+
+        let a = self;
+        let b = other;
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        match (a.null_count(), b.null_count()) {
+            (0, 0) => {
+                let iters_a = splitted_a
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                let iters_b = splitted_b
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, true)
+            }
+            _ => {
+                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, true)
+            }
+        }
+    }
 }
 
 impl HashJoin<Utf8Type> for Utf8Chunked {
@@ -730,6 +1093,62 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
             }
         }
     }
+
+    fn hash_join_semi(&self, other: &Utf8Chunked) -> Vec<u32> {
+        let n_threads = n_join_threads();
+
+        let a = self;
+        let b = other;
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        match (a.null_count(), b.null_count()) {
+            (0, 0) => {
+                let iters_a = splitted_a
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                let iters_b = splitted_b
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, false)
+            }
+            _ => {
+                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, false)
+            }
+        }
+    }
+
+    fn hash_join_anti(&self, other: &Utf8Chunked) -> Vec<u32> {
+        let n_threads = n_join_threads();
+
+        let a = self;
+        let b = other;
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        match (a.null_count(), b.null_count()) {
+            (0, 0) => {
+                let iters_a = splitted_a
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                let iters_b = splitted_b
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, true)
+            }
+            _ => {
+                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                hash_join_tuples_semi_anti_threaded(iters_a, iters_b, true)
+            }
+        }
+    }
 }
 
 pub trait ZipOuterJoinColumn {
@@ -816,6 +1235,53 @@ macro_rules! impl_zip_outer_join {
 impl_zip_outer_join!(BooleanChunked);
 impl_zip_outer_join!(Utf8Chunked);
 
+/// Formats a single column's `i`th value into `row_key`'s composite key string. Floats go
+/// through `FloatJoinKey::float_join_key` first, the same canonicalization the single-key
+/// join path applies (`-0.0` folds to `0.0`, and -- unless `POLARS_JOIN_NAN_EQ=0` -- every
+/// NaN payload folds to one canonical NaN), so two rows whose float key would join under
+/// the single-key path also match here instead of differing by sign/payload formatting.
+fn format_join_key_value(s: &Series, i: usize, join_nans: bool) -> String {
+    match s.dtype() {
+        DataType::Float32 => format!("{:?}", s.f32().unwrap().get(i).map(|v| v.float_join_key(join_nans))),
+        DataType::Float64 => format!("{:?}", s.f64().unwrap().get(i).map(|v| v.float_join_key(join_nans))),
+        _ => format!("{}", s.get(i)),
+    }
+}
+
+/// Builds a composite key string from a row's values across all key columns, the same
+/// `row_key` pattern `groupby_multiple_columns` (`frame/group_by.rs`) uses for multi-column
+/// group keys: joining each column's formatted value with a control character separator so
+/// e.g. `("ab", "c")` and `("a", "bc")` can't be concatenated into the same key string.
+fn row_key(cols: &[Series], i: usize, join_nans: bool) -> String {
+    cols.iter()
+        .map(|s| format_join_key_value(s, i, join_nans))
+        .collect::<Vec<_>>()
+        .join("\u{1}")
+}
+
+/// Multi-key semi/anti join: the indices into `left_cols` whose row of values (across
+/// all key columns, in order) does (`anti = false`) or does not (`anti = true`) occur
+/// anywhere among `right_cols`'s rows.
+///
+/// NOTE: the single-key path reuses `HashJoin::hash_join_semi`/`hash_join_anti`, backed
+/// by `prepare_hashed_relation_threaded`. The multi-key inner/left/outer paths above are
+/// backed by this module's sibling `multiple_keys` module, which isn't part of this
+/// snapshot, so there's no existing multi-column row-hasher to call into here. Instead
+/// this compares rows by an actual composite key (`row_key`, grouped in a real
+/// `HashSet<String>`) rather than a collapsed hash digest, so two different key tuples
+/// can never be mistaken for a match just because they happen to hash-collide.
+fn semi_anti_join_multiple_keys(left_cols: &[Series], right_cols: &[Series], anti: bool) -> Vec<u32> {
+    let n_left = left_cols.first().map(|s| s.len()).unwrap_or(0);
+    let n_right = right_cols.first().map(|s| s.len()).unwrap_or(0);
+    let join_nans = join_nans_equal();
+
+    let right_keys: HashSet<String> = (0..n_right).map(|i| row_key(right_cols, i, join_nans)).collect();
+
+    (0..n_left as u32)
+        .filter(|&i| right_keys.contains(&row_key(left_cols, i as usize, join_nans)) != anti)
+        .collect()
+}
+
 impl DataFrame {
     /// Utility method to finish a join.
     fn finish_join(&self, mut df_left: DataFrame, mut df_right: DataFrame) -> Result<DataFrame> {
@@ -874,6 +1340,12 @@ impl DataFrame {
                 JoinType::Outer => {
                     self.outer_join(other, selected_left[0].name(), selected_right[0].name())
                 }
+                JoinType::Semi => {
+                    self.semi_join(other, selected_left[0].name(), selected_right[0].name())
+                }
+                JoinType::Anti => {
+                    self.anti_join(other, selected_left[0].name(), selected_right[0].name())
+                }
             };
         }
 
@@ -963,6 +1435,14 @@ impl DataFrame {
                 }
                 self.finish_join(df_left, df_right)
             }
+            JoinType::Semi => {
+                let join_tuples = semi_anti_join_multiple_keys(&selected_left, &selected_right, false);
+                Ok(unsafe { self.take_iter_unchecked(join_tuples.into_iter().map(|i| i as usize)) })
+            }
+            JoinType::Anti => {
+                let join_tuples = semi_anti_join_multiple_keys(&selected_left, &selected_right, true);
+                Ok(unsafe { self.take_iter_unchecked(join_tuples.into_iter().map(|i| i as usize)) })
+            }
         }
     }
 
@@ -1093,6 +1573,52 @@ impl DataFrame {
         df_left.hstack_mut(&[s])?;
         self.finish_join(df_left, df_right)
     }
+
+    /// Filter `self` down to the rows whose `left_on` key has a match in `other`'s
+    /// `right_on` column, keeping only `self`'s columns (`WHERE left_on IN (...)`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn semi_join_dfs(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     left.semi_join(right, "join_column_left", "join_column_right")
+    /// }
+    /// ```
+    pub fn semi_join(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+    ) -> Result<DataFrame> {
+        let s_left = self.column(left_on)?;
+        let s_right = other.column(right_on)?;
+        let join_tuples = s_left.hash_join_semi(s_right);
+        Ok(unsafe { self.take_iter_unchecked(join_tuples.iter().map(|i| *i as usize)) })
+    }
+
+    /// Filter `self` down to the rows whose `left_on` key has no match in `other`'s
+    /// `right_on` column, keeping only `self`'s columns (`WHERE left_on NOT IN (...)`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn anti_join_dfs(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     left.anti_join(right, "join_column_left", "join_column_right")
+    /// }
+    /// ```
+    pub fn anti_join(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+    ) -> Result<DataFrame> {
+        let s_left = self.column(left_on)?;
+        let s_right = other.column(right_on)?;
+        let join_tuples = s_left.hash_join_anti(s_right);
+        Ok(unsafe { self.take_iter_unchecked(join_tuples.iter().map(|i| *i as usize)) })
+    }
 }
 
 #[cfg(test)]
@@ -1184,6 +1710,75 @@ mod test {
         assert_eq!(joined.column("days").unwrap().sum::<i32>(), Some(7));
     }
 
+    #[test]
+    fn test_outer_join_deterministic_order() {
+        let (temp, rain) = create_frames();
+        std::env::set_var("POLARS_JOIN_DETERMINISTIC", "1");
+
+        let first: Vec<_> = temp
+            .outer_join(&rain, "days", "days")
+            .unwrap()
+            .column("days")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_iter()
+            .collect();
+
+        for _ in 0..10 {
+            let days: Vec<_> = temp
+                .outer_join(&rain, "days", "days")
+                .unwrap()
+                .column("days")
+                .unwrap()
+                .i32()
+                .unwrap()
+                .into_iter()
+                .collect();
+            assert_eq!(days, first);
+        }
+        std::env::remove_var("POLARS_JOIN_DETERMINISTIC");
+    }
+
+    #[test]
+    fn test_semi_anti_join() {
+        let (temp, rain) = create_frames();
+
+        // "days" 1 and 2 have a match in `rain`, day 0 does not.
+        let semi = temp.semi_join(&rain, "days", "days").unwrap();
+        assert_eq!(semi.height(), 2);
+        assert_eq!(semi.column("days").unwrap().sum::<i32>(), Some(3));
+
+        let anti = temp.anti_join(&rain, "days", "days").unwrap();
+        assert_eq!(anti.height(), 1);
+        assert_eq!(anti.column("days").unwrap().sum::<i32>(), Some(0));
+
+        // a semi/anti join never duplicates a left row, unlike an inner join.
+        assert_eq!(semi.height() + anti.height(), temp.height());
+    }
+
+    #[test]
+    fn test_float_join_key_canonicalizes_negative_zero_and_nan() {
+        // A NaN with a different payload than `f64::NAN`, so the two only compare equal
+        // once NaN bit patterns are canonicalized, not because they happen to collide.
+        let other_nan = f64::from_bits(f64::NAN.to_bits() ^ 1);
+
+        let left = Series::new("a", &[0.0f64, -0.0, f64::NAN, 1.0]);
+        let right = Series::new("b", &[-0.0f64, other_nan]);
+        let left = DataFrame::new(vec![left]).unwrap();
+        let right = DataFrame::new(vec![right]).unwrap();
+
+        // `0.0` and `-0.0` must join to each other, and by default so must two NaNs.
+        std::env::remove_var("POLARS_JOIN_NAN_EQ");
+        let joined = left.inner_join(&right, "a", "b").unwrap();
+        assert_eq!(joined.height(), 3); // 0.0<->-0.0, -0.0<->-0.0, NaN<->other_nan
+
+        std::env::set_var("POLARS_JOIN_NAN_EQ", "0");
+        let joined = left.inner_join(&right, "a", "b").unwrap();
+        assert_eq!(joined.height(), 2); // only the two zeros join; the NaNs no longer match
+        std::env::remove_var("POLARS_JOIN_NAN_EQ");
+    }
+
     #[test]
     fn test_join_with_nulls() {
         let dts = &[20, 21, 22, 23, 24, 25, 27, 28];
@@ -1298,6 +1893,34 @@ mod test {
             .series_equal_missing(joined_outer.column("ham").unwrap()));
     }
 
+    #[test]
+    fn test_multi_key_semi_anti_join() {
+        let (df_a, df_b) = get_dfs();
+
+        // rows 0, 2, 3 of df_a have a matching (a, b) pair somewhere in df_b's
+        // (foo, bar); row 1 (2, "b") does not.
+        let semi = df_a
+            .join(&df_b, &["a", "b"], &["foo", "bar"], JoinType::Semi)
+            .unwrap();
+        assert_eq!(semi.height(), 3);
+        assert_eq!(
+            Vec::from(semi.column("c").unwrap().i32().unwrap()),
+            &[Some(0), Some(2), Some(3)]
+        );
+
+        let anti = df_a
+            .join(&df_b, &["a", "b"], &["foo", "bar"], JoinType::Anti)
+            .unwrap();
+        assert_eq!(anti.height(), 1);
+        assert_eq!(
+            Vec::from(anti.column("c").unwrap().i32().unwrap()),
+            &[Some(1)]
+        );
+
+        // a semi/anti join never duplicates a left row, unlike an inner join.
+        assert_eq!(semi.height() + anti.height(), df_a.height());
+    }
+
     #[test]
     fn test_join_categorical() {
         toggle_string_cache(true);
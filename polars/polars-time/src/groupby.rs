@@ -3,11 +3,57 @@ use crate::calendar::timestamp_ns_to_datetime;
 use crate::duration::Duration;
 use crate::window::Window;
 
-pub fn groupby(window: Window, time: &[i64]) -> Vec<Vec<u32>> {
+/// Which edge(s) of a window's `[start, stop]` bound are included when testing
+/// membership. Without this, a timestamp landing exactly on a boundary (e.g. the
+/// `1:00:30` sample sitting on the edge between two 30-second windows) is tested with a
+/// single hard-coded rule and can be misassigned -- counted in two windows, or dropped
+/// from both, depending on which edge it lands on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClosedWindow {
+    /// `start <= t < stop`
+    Left,
+    /// `start < t <= stop`
+    Right,
+    /// `start <= t <= stop`
+    Both,
+    /// `start < t < stop`
+    None,
+}
+
+impl ClosedWindow {
+    #[inline]
+    fn is_member(self, start: i64, stop: i64, t: i64) -> bool {
+        match self {
+            ClosedWindow::Left => start <= t && t < stop,
+            ClosedWindow::Right => start < t && t <= stop,
+            ClosedWindow::Both => start <= t && t <= stop,
+            ClosedWindow::None => start < t && t < stop,
+        }
+    }
+}
+
+/// Which edge of a window is used as the label timestamp returned alongside its group,
+/// so downstream aggregation can name each window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Label {
+    Left,
+    Right,
+}
 
+/// Groups `time` (assumed sorted) into `window`'s overlapping bounds, using
+/// `closed_window` to decide which edge(s) of each bound are inclusive. Returns the row
+/// indices per group alongside a `Vec<i64>` of that group's label timestamp (`window`'s
+/// start or stop instant per `label`), in the same order as the groups.
+pub fn groupby(
+    window: Window,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    label: Label,
+) -> (Vec<Vec<u32>>, Vec<i64>) {
     let mut boundary = Bounds::from(time);
 
     let mut group_tuples = Vec::with_capacity(window.estimate_overlapping_bounds(boundary));
+    let mut labels = Vec::with_capacity(group_tuples.capacity());
     let mut latest_start = 0;
 
     for bi in window.get_overlapping_bounds_iter(boundary) {
@@ -17,7 +63,7 @@ pub fn groupby(window: Window, time: &[i64]) -> Vec<Vec<u32>> {
 
             match time.get(latest_start - 1) {
                 Some(ts) => {
-                    if bi.is_member(*ts) {
+                    if closed_window.is_member(bi.start, bi.stop, *ts) {
                         break
                     }
                 }
@@ -31,14 +77,18 @@ pub fn groupby(window: Window, time: &[i64]) -> Vec<Vec<u32>> {
         let mut i = latest_start;
         loop {
             group.push(i as u32);
-            if i >= time.len() || !bi.is_member(time[i]){
+            if i >= time.len() || !closed_window.is_member(bi.start, bi.stop, time[i]){
                 break
             }
             i += 1
         }
+        labels.push(match label {
+            Label::Left => bi.start,
+            Label::Right => bi.stop,
+        });
         group_tuples.push(group)
     }
-    group_tuples
+    (group_tuples, labels)
 }
 
 #[cfg(test)]
@@ -60,7 +110,7 @@ mod test {
 
         let ts = dt.iter().map(|dt| dt.timestamp_nanos()).collect::<Vec<_>>();
         let window = Window::new(Duration::from_seconds(30), Duration::from_seconds(30), Duration::from_seconds(0));
-        let gt = groupby(window, &ts);
+        let (gt, _) = groupby(window, &ts, ClosedWindow::Left, Label::Left);
 
         let expected = &[
             [0, 1, 2],
@@ -70,4 +120,41 @@ mod test {
         assert_eq!(gt, expected);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_closed_window_boundary_sample_is_not_double_counted() {
+        // A 30s window with a sample sitting exactly on the 1:00:30 boundary: with
+        // `ClosedWindow::Left` it belongs only to the window starting at 1:00:30, not the
+        // one ending there.
+        let dt = &[
+            NaiveDateTime::new(NaiveDate::from_ymd(2001, 1, 1), NaiveTime::from_hms(1, 0, 0)),
+            NaiveDateTime::new(NaiveDate::from_ymd(2001, 1, 1), NaiveTime::from_hms(1, 0, 30)),
+            NaiveDateTime::new(NaiveDate::from_ymd(2001, 1, 1), NaiveTime::from_hms(1, 1, 0)),
+        ];
+        let ts = dt.iter().map(|dt| dt.timestamp_nanos()).collect::<Vec<_>>();
+        let window = Window::new(Duration::from_seconds(30), Duration::from_seconds(30), Duration::from_seconds(0));
+
+        let (left_groups, _) = groupby(window, &ts, ClosedWindow::Left, Label::Left);
+        assert_eq!(left_groups, &[vec![0], vec![1], vec![2]]);
+
+        let (right_groups, _) = groupby(window, &ts, ClosedWindow::Right, Label::Left);
+        assert_eq!(right_groups, &[vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn test_label_chooses_start_or_stop() {
+        let dt = &[
+            NaiveDateTime::new(NaiveDate::from_ymd(2001, 1, 1), NaiveTime::from_hms(1, 0, 0)),
+            NaiveDateTime::new(NaiveDate::from_ymd(2001, 1, 1), NaiveTime::from_hms(1, 0, 15)),
+        ];
+        let ts = dt.iter().map(|dt| dt.timestamp_nanos()).collect::<Vec<_>>();
+        let window = Window::new(Duration::from_seconds(30), Duration::from_seconds(30), Duration::from_seconds(0));
+
+        let (_, left_labels) = groupby(window, &ts, ClosedWindow::Left, Label::Left);
+        let (_, right_labels) = groupby(window, &ts, ClosedWindow::Left, Label::Right);
+        assert_eq!(left_labels.len(), right_labels.len());
+        // the stop label is one window-width ahead of the start label for every group.
+        for (start, stop) in left_labels.iter().zip(right_labels.iter()) {
+            assert!(stop > start);
+        }
+    }
+}
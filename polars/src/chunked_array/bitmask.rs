@@ -0,0 +1,246 @@
+//! A bit-packed mask (one bit per row, packed into `u64` words) for combining several
+//! `ChunkCompare` predicates with fused, word-at-a-time `&`/`|`/`^`/`!` before a single
+//! `ChunkFilter` pass, instead of materializing and re-scanning an intermediate
+//! `BooleanChunked` per comparison.
+//!
+//! NOTE: this needs a `mod bitmask;` added to `chunked_array/mod.rs` to be compiled in;
+//! that file isn't part of this tree snapshot.
+use crate::prelude::*;
+
+const BITS: usize = 64;
+
+/// A bit-packed boolean mask, `len` bits packed into `ceil(len / 64)` words, alongside a
+/// parallel `valid` bitmap (1 = non-null, 0 = null) so a null input row can be told apart
+/// from a `false` one. Bits past `len` within the last word are always zero in both maps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMask {
+    words: Vec<u64>,
+    valid: Vec<u64>,
+    len: usize,
+}
+
+impl BitMask {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "BitMask index out of bounds");
+        (self.words[index / BITS] >> (index % BITS)) & 1 == 1
+    }
+
+    pub fn is_valid(&self, index: usize) -> bool {
+        assert!(index < self.len, "BitMask index out of bounds");
+        (self.valid[index / BITS] >> (index % BITS)) & 1 == 1
+    }
+
+    /// Number of set (and non-null) bits, via `u64::count_ones` per word rather than a
+    /// per-bit loop.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .zip(self.valid.iter())
+            .map(|(&w, &v)| (w & v).count_ones() as usize)
+            .sum()
+    }
+
+    /// Masks off the padding bits past `len` in a map's last word, so `count_ones`/`get`
+    /// stay consistent after an operation that could otherwise leave garbage there (e.g.
+    /// negation).
+    fn mask_trailing_bits(&self, mut words: Vec<u64>) -> Vec<u64> {
+        let last_valid_bits = self.len % BITS;
+        if last_valid_bits != 0 {
+            if let Some(last) = words.last_mut() {
+                *last &= (1u64 << last_valid_bits) - 1;
+            }
+        }
+        words
+    }
+
+    fn zip_words(
+        &self,
+        other: &BitMask,
+        value_op: impl Fn(u64, u64) -> u64,
+        valid_op: impl Fn(u64, u64) -> u64,
+    ) -> BitMask {
+        assert_eq!(self.len, other.len, "BitMask lengths don't match");
+        let words = self
+            .words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(&a, &b)| value_op(a, b))
+            .collect();
+        let valid = self
+            .valid
+            .iter()
+            .zip(other.valid.iter())
+            .map(|(&a, &b)| valid_op(a, b))
+            .collect();
+        BitMask {
+            words,
+            valid: self.mask_trailing_bits(valid),
+            len: self.len,
+        }
+    }
+
+    /// Converts back to a `BooleanChunked`, `None` on rows this mask marked invalid.
+    pub fn into_boolean_chunked(&self, name: &str) -> BooleanChunked {
+        BooleanChunked::new_from_opt_slice(
+            name,
+            &(0..self.len)
+                .map(|i| self.is_valid(i).then(|| self.get(i)))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Treats a null entry as unset (`false`) in the value bitmap, matching how a mask with
+/// nulls is already treated elsewhere (e.g. `ChunkFilter::filter`, where a null mask entry
+/// drops the row) -- but keeps it marked invalid in the parallel `valid` bitmap, so an
+/// operation that actually depends on knowing "unknown" rather than "false" (`!`, `^`) can
+/// still tell the two apart.
+impl From<&BooleanChunked> for BitMask {
+    fn from(ca: &BooleanChunked) -> Self {
+        let len = ca.len();
+        let n_words = (len + BITS - 1) / BITS;
+        let mut words = vec![0u64; n_words];
+        let mut valid = vec![0u64; n_words];
+        for (i, v) in ca.into_iter().enumerate() {
+            if v.unwrap_or(false) {
+                words[i / BITS] |= 1 << (i % BITS);
+            }
+            if v.is_some() {
+                valid[i / BITS] |= 1 << (i % BITS);
+            }
+        }
+        BitMask { words, valid, len }
+    }
+}
+
+impl std::ops::BitAnd for &BitMask {
+    type Output = BitMask;
+    /// `false` absorbs `null` the same way `ChunkFilter::filter` treats a null mask entry
+    /// as a dropped (i.e. `false`) row, so the result is never null: it inherits the
+    /// value-bit semantics of plain `&` over the already-null-collapsed value bitmaps.
+    fn bitand(self, rhs: &BitMask) -> BitMask {
+        self.zip_words(rhs, |a, b| a & b, |_, _| u64::MAX)
+    }
+}
+
+impl std::ops::BitOr for &BitMask {
+    type Output = BitMask;
+    /// Same reasoning as `BitAnd`: a null operand collapses to `false` before the `|`,
+    /// which matches `ChunkFilter::filter`'s null-drops-row behavior, so the result is
+    /// never null.
+    fn bitor(self, rhs: &BitMask) -> BitMask {
+        self.zip_words(rhs, |a, b| a | b, |_, _| u64::MAX)
+    }
+}
+
+impl std::ops::BitXor for &BitMask {
+    type Output = BitMask;
+    /// Unlike `&`/`|`, `^` has no absorbing value: `null ^ true` and `null ^ false` are
+    /// both unknown, so a null operand always makes the result null (both maps' `valid`
+    /// bits `AND`ed together) instead of silently resolving to a concrete `bool`.
+    fn bitxor(self, rhs: &BitMask) -> BitMask {
+        self.zip_words(rhs, |a, b| a ^ b, |a, b| a & b)
+    }
+}
+
+impl std::ops::Not for &BitMask {
+    type Output = BitMask;
+    /// `NOT null` is null (Kleene logic has no way to resolve an unknown value by
+    /// negating it), so `valid` passes through unchanged -- only the value bits flip.
+    fn not(self) -> BitMask {
+        let words = self.mask_trailing_bits(self.words.iter().map(|w| !w).collect());
+        BitMask {
+            words,
+            valid: self.valid.clone(),
+            len: self.len,
+        }
+    }
+}
+
+macro_rules! impl_boolean_chunked_bitop {
+    ($trait:ident, $method:ident) => {
+        impl std::ops::$trait for BooleanChunked {
+            type Output = BooleanChunked;
+            fn $method(self, rhs: BooleanChunked) -> BooleanChunked {
+                let name = self.name().to_string();
+                let lhs = BitMask::from(&self);
+                let rhs = BitMask::from(&rhs);
+                std::ops::$trait::$method(&lhs, &rhs).into_boolean_chunked(&name)
+            }
+        }
+    };
+}
+
+impl_boolean_chunked_bitop!(BitAnd, bitand);
+impl_boolean_chunked_bitop!(BitOr, bitor);
+impl_boolean_chunked_bitop!(BitXor, bitxor);
+
+impl std::ops::Not for BooleanChunked {
+    type Output = BooleanChunked;
+    fn not(self) -> BooleanChunked {
+        let name = self.name().to_string();
+        (!&BitMask::from(&self)).into_boolean_chunked(&name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_boolean_chunked() {
+        let ca = BooleanChunked::new_from_slice("a", &[true, false, true, false]);
+        let mask = BitMask::from(&ca);
+        assert_eq!(mask.count_ones(), 2);
+        assert_eq!(mask.into_boolean_chunked("a"), ca);
+    }
+
+    #[test]
+    fn combinators_fuse_predicates() {
+        let a = BooleanChunked::new_from_slice("a", &[true, true, false, false]);
+        let b = BooleanChunked::new_from_slice("b", &[true, false, true, false]);
+
+        let and = a.clone() & b.clone();
+        assert_eq!(Vec::from(&and), [Some(true), Some(false), Some(false), Some(false)]);
+
+        let or = a.clone() | b.clone();
+        assert_eq!(Vec::from(&or), [Some(true), Some(true), Some(true), Some(false)]);
+
+        let not_a = !a;
+        assert_eq!(Vec::from(&not_a), [Some(false), Some(false), Some(true), Some(true)]);
+    }
+
+    #[test]
+    fn popcount_spans_multiple_words() {
+        let bools: Vec<bool> = (0..130).map(|i| i % 3 == 0).collect();
+        let ca = BooleanChunked::new_from_slice("a", &bools);
+        let mask = BitMask::from(&ca);
+        assert_eq!(mask.count_ones(), bools.iter().filter(|&&v| v).count());
+    }
+
+    #[test]
+    fn not_and_xor_keep_null_rows_null() {
+        let a = BooleanChunked::new_from_opt_slice("a", &[Some(true), Some(false), None, None]);
+        let b = BooleanChunked::new_from_opt_slice("b", &[Some(true), None, Some(true), None]);
+
+        let not_a = !a.clone();
+        assert_eq!(
+            Vec::from(&not_a),
+            [Some(false), Some(true), None, None]
+        );
+
+        let xor = a ^ b;
+        assert_eq!(
+            Vec::from(&xor),
+            [Some(false), None, None, None]
+        );
+    }
+}
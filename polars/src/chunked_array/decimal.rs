@@ -0,0 +1,323 @@
+//! Rescaling arithmetic for fixed-point `Decimal(precision, scale)` values stored as
+//! `i128`.
+//!
+//! NOTE: the request behind this file asks for `DecimalChunked::cast` to support
+//! arbitrary scale changes between two `DataType::Decimal` types, validating the
+//! rescaled value against the destination precision the same way `into_decimal` does.
+//! This crate has no `DecimalChunked` or `DataType::Decimal`; `DecimalChunked` and
+//! `into_decimal` are defined in `polars-core` (`chunked_array/logical/decimal.rs`),
+//! whose `cast` there accepts same-scale casts only. What follows is the rescaling and
+//! precision-check arithmetic for a scale-changing cast (`rescale_i128`,
+//! `fits_precision`, `cast_decimal`); nothing in this crate calls it.
+use crate::prelude::*;
+
+/// Rescales a fixed-point `i128` from `scale_src` to `scale_dst` decimal places.
+///
+/// `delta = scale_dst - scale_src`. A positive `delta` multiplies by `10^delta`
+/// (erroring on `i128` overflow); a negative `delta` divides by `10^(-delta)` using
+/// half-away-from-zero rounding, i.e. the dividend is nudged by half the divisor toward
+/// its own sign before the integer division truncates.
+pub fn rescale_i128(value: i128, scale_src: u8, scale_dst: u8) -> Result<i128> {
+    let delta = scale_dst as i32 - scale_src as i32;
+    if delta == 0 {
+        return Ok(value);
+    }
+    if delta > 0 {
+        let factor = 10i128.checked_pow(delta as u32).ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                format!(
+                    "rescaling {} from scale {} to scale {} overflows i128",
+                    value, scale_src, scale_dst
+                )
+                .into(),
+            )
+        })?;
+        value.checked_mul(factor).ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                format!(
+                    "rescaling {} from scale {} to scale {} overflows i128",
+                    value, scale_src, scale_dst
+                )
+                .into(),
+            )
+        })
+    } else {
+        let divisor = 10i128.checked_pow((-delta) as u32).ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                format!(
+                    "rescaling {} from scale {} to scale {} overflows i128",
+                    value, scale_src, scale_dst
+                )
+                .into(),
+            )
+        })?;
+        let half = divisor / 2;
+        let nudged = if value >= 0 {
+            value + half
+        } else {
+            value - half
+        };
+        Ok(nudged / divisor)
+    }
+}
+
+/// Whether `value` fits `precision` decimal digits, i.e. `|value| < 10^precision`;
+/// mirrors the bound `into_decimal` checks a freshly constructed `Decimal` value
+/// against. Errors if `10^precision` itself overflows `i128`.
+pub fn fits_precision(value: i128, precision: u8) -> Result<bool> {
+    let bound = 10i128.checked_pow(precision as u32).ok_or_else(|| {
+        PolarsError::InvalidOperation(
+            format!("precision {} overflows i128 for a fits-precision check", precision).into(),
+        )
+    })?;
+    Ok(value.abs() < bound)
+}
+
+/// Rescales `value` from `scale_src` to `(precision_dst, scale_dst)`,
+/// erroring if the rescaled value no longer fits `precision_dst`. Null slots are the
+/// caller's responsibility to pass through unchanged before reaching this function.
+pub fn cast_decimal(
+    value: i128,
+    scale_src: u8,
+    precision_dst: u8,
+    scale_dst: u8,
+) -> Result<i128> {
+    let rescaled = rescale_i128(value, scale_src, scale_dst)?;
+    if fits_precision(rescaled, precision_dst)? {
+        Ok(rescaled)
+    } else {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "{} does not fit Decimal({}, {})",
+                rescaled, precision_dst, scale_dst
+            )
+            .into(),
+        ))
+    }
+}
+
+/// A fixed-point decimal value, `value * 10^-scale`, with `precision` decimal digits of
+/// capacity (`|value| < 10^precision`).
+///
+/// NOTE: this is a single-value type; the scale/precision propagation rules of its
+/// `Add`/`Sub`/`Mul`/`Div` impls (and their scalar variants) apply per element, and
+/// nothing in this crate applies them to a chunked array (see the module doc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal {
+    pub value: i128,
+    pub precision: u8,
+    pub scale: u8,
+}
+
+impl Decimal {
+    pub fn new(value: i128, precision: u8, scale: u8) -> Self {
+        Decimal {
+            value,
+            precision,
+            scale,
+        }
+    }
+
+    fn overflow(op: &str) -> PolarsError {
+        PolarsError::InvalidOperation(format!("decimal {} overflows i128", op).into())
+    }
+}
+
+/// Aligns both operands to `max(scale_a, scale_b)` (rescaling the smaller-scale side),
+/// then adds the underlying `i128`s. Result scale is that max; result precision is
+/// `max(prec_a, prec_b) + 1`, leaving room for a carry digit.
+impl std::ops::Add for Decimal {
+    type Output = Result<Decimal>;
+    fn add(self, rhs: Decimal) -> Result<Decimal> {
+        let scale = self.scale.max(rhs.scale);
+        let a = rescale_i128(self.value, self.scale, scale)?;
+        let b = rescale_i128(rhs.value, rhs.scale, scale)?;
+        let value = a.checked_add(b).ok_or_else(|| Self::overflow("add"))?;
+        Ok(Decimal::new(value, self.precision.max(rhs.precision) + 1, scale))
+    }
+}
+
+/// The `Sub` mirror of [`Decimal`]'s `Add` impl.
+impl std::ops::Sub for Decimal {
+    type Output = Result<Decimal>;
+    fn sub(self, rhs: Decimal) -> Result<Decimal> {
+        let scale = self.scale.max(rhs.scale);
+        let a = rescale_i128(self.value, self.scale, scale)?;
+        let b = rescale_i128(rhs.value, rhs.scale, scale)?;
+        let value = a.checked_sub(b).ok_or_else(|| Self::overflow("sub"))?;
+        Ok(Decimal::new(value, self.precision.max(rhs.precision) + 1, scale))
+    }
+}
+
+/// Multiplies the underlying `i128`s directly (no rescaling needed: the product of two
+/// fixed-point values is already exact at the summed scale). Result scale is
+/// `scale_a + scale_b`; result precision is `prec_a + prec_b`.
+impl std::ops::Mul for Decimal {
+    type Output = Result<Decimal>;
+    fn mul(self, rhs: Decimal) -> Result<Decimal> {
+        let value = self
+            .value
+            .checked_mul(rhs.value)
+            .ok_or_else(|| Self::overflow("mul"))?;
+        Ok(Decimal::new(
+            value,
+            self.precision + rhs.precision,
+            self.scale + rhs.scale,
+        ))
+    }
+}
+
+/// Pre-scales the numerator by `10^scale_b` so the integer quotient lands at the result
+/// scale (`scale_a`) before truncating, rather than losing the divisor's own scale.
+impl std::ops::Div for Decimal {
+    type Output = Result<Decimal>;
+    fn div(self, rhs: Decimal) -> Result<Decimal> {
+        if rhs.value == 0 {
+            return Err(PolarsError::InvalidOperation(
+                "attempted to divide a Decimal by zero".into(),
+            ));
+        }
+        let factor = 10i128
+            .checked_pow(rhs.scale as u32)
+            .ok_or_else(|| Self::overflow("div"))?;
+        let numerator = self
+            .value
+            .checked_mul(factor)
+            .ok_or_else(|| Self::overflow("div"))?;
+        Ok(Decimal::new(
+            numerator / rhs.value,
+            self.precision.max(rhs.precision),
+            self.scale,
+        ))
+    }
+}
+
+macro_rules! impl_decimal_scalar_op {
+    ($trait:ident, $method:ident) => {
+        impl std::ops::$trait<i128> for Decimal {
+            type Output = Result<Decimal>;
+
+            /// The scalar is treated as a whole number (`scale == 0`) at `self`'s
+            /// precision before the op is applied.
+            fn $method(self, rhs: i128) -> Result<Decimal> {
+                std::ops::$trait::$method(self, Decimal::new(rhs, self.precision, 0))
+            }
+        }
+    };
+}
+
+impl_decimal_scalar_op!(Add, add);
+impl_decimal_scalar_op!(Sub, sub);
+impl_decimal_scalar_op!(Mul, mul);
+impl_decimal_scalar_op!(Div, div);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rescale_widens_scale_exactly() {
+        // 12.34 at scale 2 -> scale 4
+        assert_eq!(rescale_i128(1234, 2, 4).unwrap(), 123400);
+    }
+
+    #[test]
+    fn rescale_narrows_scale_with_rounding() {
+        // 12.345 at scale 3 -> scale 2, half-away-from-zero rounds to 12.35
+        assert_eq!(rescale_i128(12345, 3, 2).unwrap(), 1235);
+        // -12.345 rounds to -12.35
+        assert_eq!(rescale_i128(-12345, 3, 2).unwrap(), -1235);
+        // 12.344 rounds down to 12.34
+        assert_eq!(rescale_i128(12344, 3, 2).unwrap(), 1234);
+    }
+
+    #[test]
+    fn rescale_same_scale_is_identity() {
+        assert_eq!(rescale_i128(777, 2, 2).unwrap(), 777);
+    }
+
+    #[test]
+    fn rescale_widen_rejects_i128_overflow() {
+        assert!(rescale_i128(i128::MAX, 0, 1).is_err());
+    }
+
+    #[test]
+    fn rescale_rejects_exponent_overflow_before_any_multiply() {
+        // 10^40 alone overflows i128 (max ~1.7e38), regardless of `value`'s magnitude --
+        // this must error out of `checked_pow` rather than panicking/wrapping.
+        assert!(rescale_i128(1, 0, 40).is_err());
+        assert!(rescale_i128(1, 40, 0).is_err());
+    }
+
+    #[test]
+    fn fits_precision_rejects_exponent_overflow() {
+        assert!(fits_precision(1, 40).is_err());
+        assert!(fits_precision(1, 38).unwrap());
+    }
+
+    #[test]
+    fn cast_decimal_rejects_values_that_no_longer_fit() {
+        // 999 at scale 0 widened to scale 1 becomes 9990, which needs precision 4
+        assert!(cast_decimal(999, 0, 3, 1).is_err());
+        assert_eq!(cast_decimal(999, 0, 4, 1).unwrap(), 9990);
+    }
+
+    #[test]
+    fn decimal_add_aligns_scale_and_widens_precision() {
+        // 1.5 (prec 2, scale 1) + 0.25 (prec 3, scale 2) = 1.75 (prec 4, scale 2)
+        let a = Decimal::new(15, 2, 1);
+        let b = Decimal::new(25, 3, 2);
+        let sum = (a + b).unwrap();
+        assert_eq!(sum, Decimal::new(175, 4, 2));
+    }
+
+    #[test]
+    fn decimal_sub_aligns_scale() {
+        // 1.50 - 0.25 = 1.25
+        let a = Decimal::new(150, 3, 2);
+        let b = Decimal::new(25, 3, 2);
+        let diff = (a - b).unwrap();
+        assert_eq!(diff, Decimal::new(125, 4, 2));
+    }
+
+    #[test]
+    fn decimal_mul_sums_scale_and_precision() {
+        // 1.5 (prec 2, scale 1) * 0.25 (prec 3, scale 2) = 0.375 (prec 5, scale 3)
+        let a = Decimal::new(15, 2, 1);
+        let b = Decimal::new(25, 3, 2);
+        let product = (a * b).unwrap();
+        assert_eq!(product, Decimal::new(375, 5, 3));
+    }
+
+    #[test]
+    fn decimal_div_lands_at_numerator_scale() {
+        // 10.00 (scale 2) / 4.0 (scale 1) = 2.50 (scale 2)
+        let a = Decimal::new(1000, 4, 2);
+        let b = Decimal::new(40, 2, 1);
+        let quotient = (a / b).unwrap();
+        assert_eq!(quotient, Decimal::new(250, 4, 2));
+    }
+
+    #[test]
+    fn decimal_div_rejects_zero_divisor() {
+        let a = Decimal::new(100, 3, 1);
+        let zero = Decimal::new(0, 3, 1);
+        assert!((a / zero).is_err());
+    }
+
+    #[test]
+    fn decimal_scalar_add() {
+        // 1.50 + 3 = 4.50
+        let a = Decimal::new(150, 3, 2);
+        let sum = (a + 3).unwrap();
+        assert_eq!(sum, Decimal::new(450, 4, 2));
+    }
+
+    #[test]
+    fn decimal_checked_ops_reject_i128_overflow() {
+        let max = Decimal::new(i128::MAX, 38, 0);
+        let one = Decimal::new(1, 1, 0);
+        assert!((max + one).is_err());
+        assert!((max * max).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 //! Traits for miscellaneous operations on ChunkedArray
+use crate::chunked_array::bitmask::BitMask;
 use crate::chunked_array::builder::get_large_list_builder;
 use crate::chunked_array::kernels;
 use crate::chunked_array::kernels::vendor::filter::filter_primitive_array;
@@ -6,10 +7,11 @@ use crate::prelude::*;
 use crate::utils::Xob;
 use arrow::array::ArrayRef;
 use itertools::Itertools;
-use num::{Num, NumCast};
-use std::cmp::Ordering;
+use num::{Num, NumCast, ToPrimitive};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
 use std::marker::Sized;
-use std::ops::{Add, Div};
+use std::ops::{Add, Div, Sub};
 use std::sync::Arc;
 
 /// Random access
@@ -232,23 +234,17 @@ pub trait ChunkUnique<T> {
 
 /// Sort operations on `ChunkedArray`.
 pub trait ChunkSort<T> {
-    /// Returned a sorted `ChunkedArray`.
-    fn sort(&self, reverse: bool) -> ChunkedArray<T>;
+    /// Returned a sorted `ChunkedArray`. `nulls_last` controls whether nulls end up at
+    /// the front or the back, independent of `reverse`.
+    fn sort(&self, reverse: bool, nulls_last: bool) -> ChunkedArray<T>;
 
     /// Sort this array in place.
-    fn sort_in_place(&mut self, reverse: bool);
+    fn sort_in_place(&mut self, reverse: bool, nulls_last: bool);
 
-    /// Retrieve the indexes needed to sort this array.
-    fn argsort(&self, reverse: bool) -> Vec<usize>;
-}
-
-fn sort_partial<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
-    match (a, b) {
-        (Some(a), Some(b)) => a.partial_cmp(b).expect("could not compare"),
-        (None, Some(_)) => Ordering::Less,
-        (Some(_), None) => Ordering::Greater,
-        (None, None) => Ordering::Equal,
-    }
+    /// Retrieve the indexes needed to sort this array, suitable for `ChunkTake::take`.
+    /// Ties are broken on the original index so the result is deterministic even though
+    /// the underlying sort is unstable.
+    fn argsort(&self, reverse: bool, nulls_last: bool) -> Vec<usize>;
 }
 
 impl<T> ChunkSort<T> for ChunkedArray<T>
@@ -256,112 +252,316 @@ where
     T: PolarsNumericType,
     T::Native: std::cmp::PartialOrd,
 {
-    fn sort(&self, reverse: bool) -> ChunkedArray<T> {
-        if reverse {
-            self.into_iter()
-                .sorted_by(|a, b| sort_partial(b, a))
-                .collect()
-        } else {
-            self.into_iter()
-                .sorted_by(|a, b| sort_partial(a, b))
-                .collect()
+    fn sort(&self, reverse: bool, nulls_last: bool) -> ChunkedArray<T> {
+        let null_count = self.null_count();
+        let mut values: Vec<T::Native> = self.into_iter().flatten().collect();
+        // `sort_unstable_by` is pattern-defeating quicksort: no allocation beyond the
+        // `Vec` above, and no heapsort/merge-sort detour unless the data is adversarial.
+        values.sort_unstable_by(|a, b| {
+            let ord = a.partial_cmp(b).expect("value could not be compared, NaN?");
+            if reverse {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        if !nulls_last {
+            for _ in 0..null_count {
+                builder.append_null();
+            }
+        }
+        for v in values {
+            builder.append_value(v);
+        }
+        if nulls_last {
+            for _ in 0..null_count {
+                builder.append_null();
+            }
         }
+        builder.finish()
     }
 
-    fn sort_in_place(&mut self, reverse: bool) {
-        let sorted = self.sort(reverse);
+    fn sort_in_place(&mut self, reverse: bool, nulls_last: bool) {
+        // No null bitmap to shuffle around: sort a copy of the native values and rebuild
+        // the array from it as a single chunk, rather than rebuilding through an
+        // intermediate `Option<T::Native>` pass. The existing buffer is not mutated.
+        if let Ok(slice) = self.cont_slice() {
+            let mut values = slice.to_vec();
+            values.sort_unstable_by(|a, b| {
+                let ord = a.partial_cmp(b).expect("value could not be compared, NaN?");
+                if reverse {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            });
+            *self = ChunkedArray::new_from_slice(self.name(), &values);
+            return;
+        }
+        let sorted = self.sort(reverse, nulls_last);
         self.chunks = sorted.chunks;
     }
 
-    fn argsort(&self, reverse: bool) -> Vec<usize> {
-        if reverse {
-            self.into_iter()
-                .enumerate()
-                .sorted_by(|(_idx_a, a), (_idx_b, b)| sort_partial(b, a))
-                .map(|(idx, _v)| idx)
-                .collect()
-        } else {
-            self.into_iter()
-                .enumerate()
-                .sorted_by(|(_idx_a, a), (_idx_b, b)| sort_partial(a, b))
-                .map(|(idx, _v)| idx)
-                .collect()
-        }
+    fn argsort(&self, reverse: bool, nulls_last: bool) -> Vec<usize> {
+        let values: Vec<Option<T::Native>> = self.into_iter().collect();
+        let mut idx: Vec<usize> = (0..self.len()).collect();
+        idx.sort_unstable_by(|&a, &b| match (&values[a], &values[b]) {
+            (Some(va), Some(vb)) => {
+                let ord = va.partial_cmp(vb).expect("value could not be compared, NaN?");
+                let ord = if reverse { ord.reverse() } else { ord };
+                ord.then_with(|| a.cmp(&b))
+            }
+            (None, None) => a.cmp(&b),
+            (None, Some(_)) => {
+                if nulls_last {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            }
+            (Some(_), None) => {
+                if nulls_last {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+        });
+        idx
     }
 }
 
 macro_rules! argsort {
-    ($self:ident, $closure:expr) => {{
-        $self
-            .into_iter()
-            .enumerate()
-            .sorted_by($closure)
-            .map(|(idx, _v)| idx)
-            .collect()
+    ($self:ident, $reverse:expr, $nulls_last:expr) => {{
+        let values: Vec<_> = $self.into_iter().collect();
+        let mut idx: Vec<usize> = (0..$self.len()).collect();
+        idx.sort_unstable_by(|&a, &b| match (&values[a], &values[b]) {
+            (Some(va), Some(vb)) => {
+                let ord = va.cmp(vb);
+                let ord = if $reverse { ord.reverse() } else { ord };
+                ord.then_with(|| a.cmp(&b))
+            }
+            (None, None) => a.cmp(&b),
+            (None, Some(_)) => {
+                if $nulls_last {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            }
+            (Some(_), None) => {
+                if $nulls_last {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+        });
+        idx
     }};
 }
 
 macro_rules! sort {
-    ($self:ident, $reverse:ident) => {{
-        if $reverse {
-            $self.into_iter().sorted_by(|a, b| b.cmp(a)).collect()
-        } else {
-            $self.into_iter().sorted_by(|a, b| a.cmp(b)).collect()
+    ($self:ident, $reverse:expr, $nulls_last:expr) => {{
+        let null_count = $self.null_count();
+        let mut values: Vec<_> = $self.into_iter().flatten().collect();
+        values.sort_unstable_by(|a, b| {
+            let ord = a.cmp(b);
+            if $reverse {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+
+        let mut out: Vec<Option<_>> = Vec::with_capacity($self.len());
+        if !$nulls_last {
+            out.extend(std::iter::repeat(None).take(null_count));
         }
+        out.extend(values.into_iter().map(Some));
+        if $nulls_last {
+            out.extend(std::iter::repeat(None).take(null_count));
+        }
+        ChunkedArray::new_from_opt_slice($self.name(), &out)
     }};
 }
 
 impl ChunkSort<Utf8Type> for Utf8Chunked {
-    fn sort(&self, reverse: bool) -> Utf8Chunked {
-        sort!(self, reverse)
+    fn sort(&self, reverse: bool, nulls_last: bool) -> Utf8Chunked {
+        sort!(self, reverse, nulls_last)
     }
 
-    fn sort_in_place(&mut self, reverse: bool) {
-        let sorted = self.sort(reverse);
+    fn sort_in_place(&mut self, reverse: bool, nulls_last: bool) {
+        let sorted = self.sort(reverse, nulls_last);
         self.chunks = sorted.chunks;
     }
 
-    fn argsort(&self, reverse: bool) -> Vec<usize> {
-        if reverse {
-            argsort!(self, |(_idx_a, a), (_idx_b, b)| b.cmp(a))
-        } else {
-            argsort!(self, |(_idx_a, a), (_idx_b, b)| a.cmp(b))
-        }
+    fn argsort(&self, reverse: bool, nulls_last: bool) -> Vec<usize> {
+        argsort!(self, reverse, nulls_last)
     }
 }
 
 impl ChunkSort<LargeListType> for LargeListChunked {
-    fn sort(&self, _reverse: bool) -> Self {
+    fn sort(&self, _reverse: bool, _nulls_last: bool) -> Self {
         println!("A ListChunked cannot be sorted. Doing nothing");
         self.clone()
     }
 
-    fn sort_in_place(&mut self, _reverse: bool) {
+    fn sort_in_place(&mut self, _reverse: bool, _nulls_last: bool) {
         println!("A ListChunked cannot be sorted. Doing nothing");
     }
 
-    fn argsort(&self, _reverse: bool) -> Vec<usize> {
+    fn argsort(&self, _reverse: bool, _nulls_last: bool) -> Vec<usize> {
         println!("A ListChunked cannot be sorted. Doing nothing");
         (0..self.len()).collect()
     }
 }
 
 impl ChunkSort<BooleanType> for BooleanChunked {
-    fn sort(&self, reverse: bool) -> BooleanChunked {
-        sort!(self, reverse)
+    fn sort(&self, reverse: bool, nulls_last: bool) -> BooleanChunked {
+        sort!(self, reverse, nulls_last)
     }
 
-    fn sort_in_place(&mut self, reverse: bool) {
-        let sorted = self.sort(reverse);
+    fn sort_in_place(&mut self, reverse: bool, nulls_last: bool) {
+        let sorted = self.sort(reverse, nulls_last);
         self.chunks = sorted.chunks;
     }
 
-    fn argsort(&self, reverse: bool) -> Vec<usize> {
-        if reverse {
-            argsort!(self, |(_idx_a, a), (_idx_b, b)| b.cmp(a))
-        } else {
-            argsort!(self, |(_idx_a, a), (_idx_b, b)| a.cmp(b))
+    fn argsort(&self, reverse: bool, nulls_last: bool) -> Vec<usize> {
+        argsort!(self, reverse, nulls_last)
+    }
+}
+
+/// Extract the `k` largest/smallest values without a full sort: O(n log k) time and
+/// O(k) memory via a bounded `BinaryHeap`, versus `ChunkSort`'s O(n log n) full sort.
+/// Nulls are skipped; if fewer than `k` non-null values exist, all of them are returned.
+pub trait ChunkTopK<T> {
+    /// The `k` largest values, descending.
+    fn top_k(&self, k: usize) -> ChunkedArray<T>;
+
+    /// The `k` smallest values, ascending.
+    fn bottom_k(&self, k: usize) -> ChunkedArray<T>;
+}
+
+/// `BinaryHeap` requires `Ord`; this gives `T::Native` a total order via `partial_cmp`,
+/// the same "NaN is a bug, not a value" stance `ChunkSort` already takes.
+#[derive(PartialEq)]
+struct TotalOrd<V>(V);
+
+impl<V: PartialOrd> Eq for TotalOrd<V> {}
+
+impl<V: PartialOrd> PartialOrd for TotalOrd<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<V: PartialOrd> Ord for TotalOrd<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other)
+            .expect("value could not be compared, NaN?")
+    }
+}
+
+impl<T> ChunkTopK<T> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    fn top_k(&self, k: usize) -> ChunkedArray<T> {
+        // Min-heap of the current top-k (via `Reverse`): once it grows past `k`, the
+        // smallest of the current top-k sits at the top and gets evicted.
+        let mut heap: BinaryHeap<Reverse<TotalOrd<T::Native>>> = BinaryHeap::with_capacity(k + 1);
+        for v in self.into_iter().flatten() {
+            heap.push(Reverse(TotalOrd(v)));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        let mut values: Vec<T::Native> = heap.into_iter().map(|Reverse(TotalOrd(v))| v).collect();
+        values.sort_unstable_by(|a, b| b.partial_cmp(a).expect("value could not be compared, NaN?"));
+        ChunkedArray::new_from_slice(self.name(), &values)
+    }
+
+    fn bottom_k(&self, k: usize) -> ChunkedArray<T> {
+        // Max-heap of the current bottom-k: once it grows past `k`, the largest of the
+        // current bottom-k sits at the top and gets evicted.
+        let mut heap: BinaryHeap<TotalOrd<T::Native>> = BinaryHeap::with_capacity(k + 1);
+        for v in self.into_iter().flatten() {
+            heap.push(TotalOrd(v));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        let mut values: Vec<T::Native> = heap.into_iter().map(|TotalOrd(v)| v).collect();
+        values.sort_unstable_by(|a, b| a.partial_cmp(b).expect("value could not be compared, NaN?"));
+        ChunkedArray::new_from_slice(self.name(), &values)
+    }
+}
+
+impl ChunkTopK<Utf8Type> for Utf8Chunked {
+    fn top_k(&self, k: usize) -> Utf8Chunked {
+        let mut heap: BinaryHeap<Reverse<&str>> = BinaryHeap::with_capacity(k + 1);
+        for v in self.into_iter().flatten() {
+            heap.push(Reverse(v));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        let mut values: Vec<&str> = heap.into_iter().map(|Reverse(v)| v).collect();
+        values.sort_unstable_by(|a, b| b.cmp(a));
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), values.len());
+        for v in values {
+            builder.append_value(v).expect("could not append");
+        }
+        builder.finish()
+    }
+
+    fn bottom_k(&self, k: usize) -> Utf8Chunked {
+        let mut heap: BinaryHeap<&str> = BinaryHeap::with_capacity(k + 1);
+        for v in self.into_iter().flatten() {
+            heap.push(v);
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        let mut values: Vec<&str> = heap.into_iter().collect();
+        values.sort_unstable_by(|a, b| a.cmp(b));
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), values.len());
+        for v in values {
+            builder.append_value(v).expect("could not append");
+        }
+        builder.finish()
+    }
+}
+
+impl ChunkTopK<BooleanType> for BooleanChunked {
+    fn top_k(&self, k: usize) -> BooleanChunked {
+        let mut heap: BinaryHeap<Reverse<bool>> = BinaryHeap::with_capacity(k + 1);
+        for v in self.into_iter().flatten() {
+            heap.push(Reverse(v));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        let mut values: Vec<bool> = heap.into_iter().map(|Reverse(v)| v).collect();
+        values.sort_unstable_by(|a, b| b.cmp(a));
+        ChunkedArray::new_from_slice(self.name(), &values)
+    }
+
+    fn bottom_k(&self, k: usize) -> BooleanChunked {
+        let mut heap: BinaryHeap<bool> = BinaryHeap::with_capacity(k + 1);
+        for v in self.into_iter().flatten() {
+            heap.push(v);
+            if heap.len() > k {
+                heap.pop();
+            }
         }
+        let mut values: Vec<bool> = heap.into_iter().collect();
+        values.sort_unstable_by(|a, b| a.cmp(b));
+        ChunkedArray::new_from_slice(self.name(), &values)
     }
 }
 
@@ -372,6 +572,11 @@ pub enum FillNoneStrategy {
     Mean,
     Min,
     Max,
+    /// Interpolate linearly between the surrounding non-null values. `extrapolate`
+    /// controls what happens to a leading/trailing null run that has an anchor on only
+    /// one side: `true` forward/backward fills it from that anchor, `false` leaves it
+    /// null.
+    Linear { extrapolate: bool },
 }
 
 /// Replace None values with various strategies
@@ -382,7 +587,11 @@ pub trait ChunkFillNone<T> {
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
-    fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self>
+    /// * Linear fill (replace None by interpolating between the surrounding values)
+    ///
+    /// `limit` bounds how many consecutive Nones a Forward/Backward/Linear pass will
+    /// replace before leaving the remainder of the run null.
+    fn fill_none(&self, strategy: FillNoneStrategy, limit: Option<usize>) -> Result<Self>
     where
         Self: Sized;
 
@@ -392,81 +601,173 @@ pub trait ChunkFillNone<T> {
         Self: Sized;
 }
 
-fn fill_forward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+fn fill_forward<T>(ca: &ChunkedArray<T>, limit: Option<usize>) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
+    let max_run = limit.unwrap_or(usize::MAX);
     ca.into_iter()
-        .scan(None, |previous, opt_v| {
+        .scan((None, 0usize), |(previous, run), opt_v| {
             let val = match opt_v {
-                Some(_) => Some(opt_v),
-                None => Some(*previous),
+                Some(_) => {
+                    *previous = opt_v;
+                    *run = 0;
+                    opt_v
+                }
+                None => {
+                    if *run < max_run {
+                        *run += 1;
+                        *previous
+                    } else {
+                        None
+                    }
+                }
             };
-            *previous = opt_v;
-            val
+            Some(val)
         })
         .collect()
 }
 
 macro_rules! impl_fill_forward {
-    ($ca:ident) => {{
+    ($ca:ident, $limit:expr) => {{
+        let max_run = $limit.unwrap_or(usize::MAX);
         let ca = $ca
             .into_iter()
-            .scan(None, |previous, opt_v| {
+            .scan((None, 0usize), |(previous, run), opt_v| {
                 let val = match opt_v {
-                    Some(_) => Some(opt_v),
-                    None => Some(*previous),
+                    Some(_) => {
+                        *previous = opt_v;
+                        *run = 0;
+                        opt_v
+                    }
+                    None => {
+                        if *run < max_run {
+                            *run += 1;
+                            *previous
+                        } else {
+                            None
+                        }
+                    }
                 };
-                *previous = opt_v;
-                val
+                Some(val)
             })
             .collect();
         Ok(ca)
     }};
 }
 
-fn fill_backward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+fn fill_backward<T>(ca: &ChunkedArray<T>, limit: Option<usize>) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
-    let mut iter = ca.into_iter().peekable();
-
-    let mut builder = PrimitiveChunkedBuilder::<T>::new(ca.name(), ca.len());
-    while let Some(opt_v) = iter.next() {
-        match opt_v {
-            Some(v) => builder.append_value(v),
-            None => {
-                match iter.peek() {
-                    // end of iterator
-                    None => builder.append_null(),
-                    Some(opt_v) => builder.append_option(*opt_v),
-                }
+    let max_run = limit.unwrap_or(usize::MAX);
+    let mut values: Vec<Option<T::Native>> = ca.into_iter().collect();
+
+    let mut next = None;
+    let mut run = 0usize;
+    for slot in values.iter_mut().rev() {
+        match *slot {
+            Some(v) => {
+                next = Some(v);
+                run = 0;
             }
+            None if run < max_run => {
+                run += 1;
+                *slot = next;
+            }
+            None => {}
         }
     }
-    builder.finish()
+    ChunkedArray::new_from_opt_slice(ca.name(), &values)
 }
 
 macro_rules! impl_fill_backward {
-    ($ca:ident, $builder:ident) => {{
-        let mut iter = $ca.into_iter().peekable();
-
-        while let Some(opt_v) = iter.next() {
-            match opt_v {
-                Some(v) => $builder.append_value(v),
-                None => {
-                    match iter.peek() {
-                        // end of iterator
-                        None => $builder.append_null(),
-                        Some(opt_v) => $builder.append_option(*opt_v),
-                    }
+    ($ca:ident, $builder:ident, $limit:expr) => {{
+        let max_run = $limit.unwrap_or(usize::MAX);
+        let mut values: Vec<_> = $ca.into_iter().collect();
+
+        let mut next = None;
+        let mut run = 0usize;
+        for slot in values.iter_mut().rev() {
+            match *slot {
+                Some(v) => {
+                    next = Some(v);
+                    run = 0;
+                }
+                None if run < max_run => {
+                    run += 1;
+                    *slot = next;
                 }
+                None => {}
             }
         }
+        for v in values {
+            $builder.append_option(v);
+        }
         Ok($builder.finish())
     }};
 }
 
+/// Linearly interpolates every null run that has a non-null anchor on both sides, using
+/// `prev + (next - prev) * (i - prev_idx) / (next_idx - prev_idx)`. A leading/trailing
+/// run with an anchor on only one side is forward/backward filled from that anchor when
+/// `extrapolate` is set, otherwise left null. `limit` bounds how many nulls in a row get
+/// filled (interpolated or extrapolated) before the remainder of the run is left null.
+fn fill_linear<T>(ca: &ChunkedArray<T>, extrapolate: bool, limit: Option<usize>) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast + ToPrimitive,
+{
+    let max_run = limit.unwrap_or(usize::MAX);
+    let values: Vec<Option<T::Native>> = ca.into_iter().collect();
+    let mut out = values.clone();
+
+    let anchors: Vec<usize> = values
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|_| i))
+        .collect();
+
+    if anchors.is_empty() {
+        return ChunkedArray::new_from_opt_slice(ca.name(), &out);
+    }
+
+    if extrapolate {
+        let first = anchors[0];
+        let fill = values[first];
+        for i in first.saturating_sub(max_run)..first {
+            out[i] = fill;
+        }
+
+        let last = anchors[anchors.len() - 1];
+        let fill = values[last];
+        for i in (last + 1)..(last + 1).saturating_add(max_run).min(values.len()) {
+            out[i] = fill;
+        }
+    }
+
+    for w in anchors.windows(2) {
+        let (prev_idx, next_idx) = (w[0], w[1]);
+        let gap = next_idx - prev_idx - 1;
+        if gap == 0 {
+            continue;
+        }
+        let prev = values[prev_idx].unwrap().to_f64().unwrap();
+        let next = values[next_idx].unwrap().to_f64().unwrap();
+        let span = (next_idx - prev_idx) as f64;
+        // Only the first `max_run` positions of an over-limit gap get filled, leaving the
+        // remainder null -- the same partial-fill-then-stop behavior `fill_forward`/
+        // `fill_backward` give an over-limit run.
+        let fill_until = prev_idx + 1 + gap.min(max_run);
+        for i in (prev_idx + 1)..fill_until {
+            let t = (i - prev_idx) as f64 / span;
+            out[i] = NumCast::from(prev + (next - prev) * t);
+        }
+    }
+
+    ChunkedArray::new_from_opt_slice(ca.name(), &out)
+}
+
 fn fill_value<T>(ca: &ChunkedArray<T>, value: Option<T::Native>) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -493,19 +794,21 @@ macro_rules! impl_fill_value {
 impl<T> ChunkFillNone<T::Native> for ChunkedArray<T>
 where
     T: PolarsNumericType,
-    T::Native: Add<Output = T::Native> + PartialOrd + Div<Output = T::Native> + Num + NumCast,
+    T::Native:
+        Add<Output = T::Native> + PartialOrd + Div<Output = T::Native> + Num + NumCast + ToPrimitive,
 {
-    fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self> {
+    fn fill_none(&self, strategy: FillNoneStrategy, limit: Option<usize>) -> Result<Self> {
         // nothing to fill
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
         let ca = match strategy {
-            FillNoneStrategy::Forward => fill_forward(self),
-            FillNoneStrategy::Backward => fill_backward(self),
+            FillNoneStrategy::Forward => fill_forward(self, limit),
+            FillNoneStrategy::Backward => fill_backward(self, limit),
             FillNoneStrategy::Min => impl_fill_value!(self, self.min()),
             FillNoneStrategy::Max => impl_fill_value!(self, self.max()),
             FillNoneStrategy::Mean => impl_fill_value!(self, self.mean()),
+            FillNoneStrategy::Linear { extrapolate } => fill_linear(self, extrapolate, limit),
         };
         Ok(ca)
     }
@@ -515,18 +818,21 @@ where
 }
 
 impl ChunkFillNone<bool> for BooleanChunked {
-    fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self> {
+    fn fill_none(&self, strategy: FillNoneStrategy, limit: Option<usize>) -> Result<Self> {
         // nothing to fill
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
         let mut builder = PrimitiveChunkedBuilder::<BooleanType>::new(self.name(), self.len());
         match strategy {
-            FillNoneStrategy::Forward => impl_fill_forward!(self),
-            FillNoneStrategy::Backward => impl_fill_backward!(self, builder),
+            FillNoneStrategy::Forward => impl_fill_forward!(self, limit),
+            FillNoneStrategy::Backward => impl_fill_backward!(self, builder, limit),
             FillNoneStrategy::Min => Ok(impl_fill_value!(self, self.min().map(|v| v != 0))),
             FillNoneStrategy::Max => Ok(impl_fill_value!(self, self.max().map(|v| v != 0))),
             FillNoneStrategy::Mean => Ok(impl_fill_value!(self, self.mean().map(|v| v != 0))),
+            strat @ FillNoneStrategy::Linear { .. } => Err(PolarsError::InvalidOperation(
+                format!("Strategy {:?} not supported", strat).into(),
+            )),
         }
     }
 
@@ -536,15 +842,15 @@ impl ChunkFillNone<bool> for BooleanChunked {
 }
 
 impl ChunkFillNone<&str> for Utf8Chunked {
-    fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self> {
+    fn fill_none(&self, strategy: FillNoneStrategy, limit: Option<usize>) -> Result<Self> {
         // nothing to fill
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
         let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len());
         match strategy {
-            FillNoneStrategy::Forward => impl_fill_forward!(self),
-            FillNoneStrategy::Backward => impl_fill_backward!(self, builder),
+            FillNoneStrategy::Forward => impl_fill_forward!(self, limit),
+            FillNoneStrategy::Backward => impl_fill_backward!(self, builder, limit),
             strat => Err(PolarsError::InvalidOperation(
                 format!("Strategy {:?} not supported", strat).into(),
             )),
@@ -557,7 +863,7 @@ impl ChunkFillNone<&str> for Utf8Chunked {
 }
 
 impl ChunkFillNone<&Series> for LargeListChunked {
-    fn fill_none(&self, _strategy: FillNoneStrategy) -> Result<Self> {
+    fn fill_none(&self, _strategy: FillNoneStrategy, _limit: Option<usize>) -> Result<Self> {
         Err(PolarsError::InvalidOperation(
             "fill_none not supported for LargeList type".into(),
         ))
@@ -693,6 +999,16 @@ pub trait ChunkFilter<T> {
     fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<T>>
     where
         Self: Sized;
+
+    /// Filter with a pre-packed [`BitMask`](crate::chunked_array::bitmask::BitMask),
+    /// e.g. the fused result of `a.gt(1) & b.lt(10)`, sparing the caller a second
+    /// `BooleanChunked` comparison pass for each predicate combined into the mask.
+    fn filter_bitmask(&self, mask: &BitMask) -> Result<ChunkedArray<T>>
+    where
+        Self: Sized,
+    {
+        self.filter(&mask.into_boolean_chunked(""))
+    }
 }
 
 macro_rules! impl_filter_with_nulls_in_both {
@@ -729,9 +1045,7 @@ macro_rules! impl_filter_no_nulls_in_mask {
 macro_rules! check_filter_len {
     ($self:expr, $filter:expr) => {{
         if $self.len() != $filter.len() {
-            return Err(PolarsError::ShapeMisMatch(
-                "Filter's length differs from that of the ChunkedArray/ Series.".into(),
-            ));
+            return Err(PolarsError::LengthMismatch($self.len(), $filter.len()));
         }
     }};
 }
@@ -943,8 +1257,15 @@ where
 {
     fn shift(&self, periods: i32, fill_value: &Option<T::Native>) -> Result<ChunkedArray<T>> {
         if periods.abs() >= self.len() as i32 {
-            return Err(PolarsError::OutOfBounds(
-                format!("The value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}", periods, self.len()).into()));
+            return Err(PolarsError::OutOfBounds {
+                index: periods.unsigned_abs() as usize,
+                len: self.len(),
+            }
+            .context(format!(
+                "the value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}",
+                periods,
+                self.len()
+            )));
         }
         let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
         let amount = self.len() - periods.abs() as usize;
@@ -1001,8 +1322,15 @@ macro_rules! impl_shift {
 impl ChunkShift<BooleanType, bool> for BooleanChunked {
     fn shift(&self, periods: i32, fill_value: &Option<bool>) -> Result<BooleanChunked> {
         if periods.abs() >= self.len() as i32 {
-            return Err(PolarsError::OutOfBounds(
-                format!("The value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}", periods, self.len()).into()));
+            return Err(PolarsError::OutOfBounds {
+                index: periods.unsigned_abs() as usize,
+                len: self.len(),
+            }
+            .context(format!(
+                "the value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}",
+                periods,
+                self.len()
+            )));
         }
         let mut builder = PrimitiveChunkedBuilder::<BooleanType>::new(self.name(), self.len());
 
@@ -1018,8 +1346,15 @@ impl ChunkShift<BooleanType, bool> for BooleanChunked {
 impl ChunkShift<Utf8Type, &str> for Utf8Chunked {
     fn shift(&self, periods: i32, fill_value: &Option<&str>) -> Result<Utf8Chunked> {
         if periods.abs() >= self.len() as i32 {
-            return Err(PolarsError::OutOfBounds(
-                format!("The value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}", periods, self.len()).into()));
+            return Err(PolarsError::OutOfBounds {
+                index: periods.unsigned_abs() as usize,
+                len: self.len(),
+            }
+            .context(format!(
+                "the value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}",
+                periods,
+                self.len()
+            )));
         }
         let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len());
         fn append_fn(builder: &mut Utf8ChunkedBuilder, v: Option<&str>) {
@@ -1034,8 +1369,15 @@ impl ChunkShift<Utf8Type, &str> for Utf8Chunked {
 impl ChunkShift<LargeListType, Series> for LargeListChunked {
     fn shift(&self, periods: i32, fill_value: &Option<Series>) -> Result<LargeListChunked> {
         if periods.abs() >= self.len() as i32 {
-            return Err(PolarsError::OutOfBounds(
-                format!("The value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}", periods, self.len()).into()));
+            return Err(PolarsError::OutOfBounds {
+                index: periods.unsigned_abs() as usize,
+                len: self.len(),
+            }
+            .context(format!(
+                "the value of parameter `periods`: {} in the shift operation is larger than the length of the ChunkedArray: {}",
+                periods,
+                self.len()
+            )));
         }
         let dt = self.get_inner_dtype();
         let mut builder = get_large_list_builder(dt, self.len(), self.name());
@@ -1054,39 +1396,337 @@ impl ChunkShift<LargeListType, Series> for LargeListChunked {
     }
 }
 
+/// Running (prefix) aggregations: one output value per input row, rather than a
+/// single-value reduction.
+pub trait ChunkCumAgg<T> {
+    /// Running sum. A null carries the accumulator forward unchanged.
+    fn cumsum(&self) -> ChunkedArray<T>;
+    /// Running product. A null carries the accumulator forward unchanged.
+    fn cumprod(&self) -> ChunkedArray<T>;
+    /// Running minimum. A null carries the accumulator forward unchanged.
+    fn cummin(&self) -> ChunkedArray<T>;
+    /// Running maximum. A null carries the accumulator forward unchanged.
+    fn cummax(&self) -> ChunkedArray<T>;
+}
+
+fn cum_agg_helper<T>(
+    ca: &ChunkedArray<T>,
+    builder: &mut PrimitiveChunkedBuilder<T>,
+    mut fold: impl FnMut(T::Native, T::Native) -> T::Native,
+) where
+    T: PolarsNumericType,
+    T::Native: Copy,
+{
+    // A null slot is skipped (it doesn't perturb the running accumulator) but is
+    // itself still propagated as null in the output.
+    let mut acc: Option<T::Native> = None;
+    let mut push = |v: Option<T::Native>, builder: &mut PrimitiveChunkedBuilder<T>| match v {
+        Some(v) => {
+            acc = Some(match acc {
+                Some(prev) => fold(prev, v),
+                None => v,
+            });
+            builder.append_option(acc);
+        }
+        None => builder.append_null(),
+    };
+    match ca.cont_slice() {
+        // fast path
+        Ok(slice) => slice.iter().for_each(|v| push(Some(*v), builder)),
+        // slower path
+        _ => ca.into_iter().for_each(|opt| push(opt, builder)),
+    }
+}
+
+impl<T> ChunkCumAgg<T> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Copy + PartialOrd + std::ops::Add<Output = T::Native> + std::ops::Mul<Output = T::Native>,
+{
+    fn cumsum(&self) -> ChunkedArray<T> {
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        cum_agg_helper(self, &mut builder, |acc, v| acc + v);
+        builder.finish()
+    }
+
+    fn cumprod(&self) -> ChunkedArray<T> {
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        cum_agg_helper(self, &mut builder, |acc, v| acc * v);
+        builder.finish()
+    }
+
+    fn cummin(&self) -> ChunkedArray<T> {
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        cum_agg_helper(self, &mut builder, |acc, v| if v < acc { v } else { acc });
+        builder.finish()
+    }
+
+    fn cummax(&self) -> ChunkedArray<T> {
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        cum_agg_helper(self, &mut builder, |acc, v| if v > acc { v } else { acc });
+        builder.finish()
+    }
+}
+
+/// Rolling-window aggregations: a full-length output where position `i` summarizes the
+/// trailing window `[i - window_size + 1, i]` (or, when `center` is set, the window
+/// straddling `i`). A window with fewer than `min_periods` non-null values emits null;
+/// windows at the start of the array are allowed to be smaller than `window_size`
+/// (partial windows), so `min_periods` alone governs when a position starts producing
+/// output.
+pub trait ChunkRollApply<T> {
+    /// Rolling sum, via a running accumulator over `cont_slice()`/`into_iter()`: each
+    /// step adds the entering element and subtracts the leaving one rather than
+    /// re-summing the window from scratch.
+    fn rolling_sum(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>>;
+
+    /// Rolling mean, built on the same running sum/valid-count accumulator as
+    /// `rolling_sum`.
+    fn rolling_mean(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>>;
+
+    /// Rolling min via a monotonic deque of `(index, value)`: each element is pushed
+    /// and popped at most once, giving O(n) total work instead of O(n * window_size).
+    fn rolling_min(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>>;
+
+    /// Rolling max, the mirror of `rolling_min`.
+    fn rolling_max(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>>;
+}
+
+fn rolling_window_guard<T>(ca: &ChunkedArray<T>, window_size: usize) -> Result<()> {
+    if window_size == 0 {
+        return Err(PolarsError::InvalidOperation(
+            "`window_size` must be greater than 0".into(),
+        ));
+    }
+    if window_size > ca.len() {
+        return Err(PolarsError::OutOfBounds {
+            index: window_size,
+            len: ca.len(),
+        }
+        .context(format!(
+            "the value of parameter `window_size`: {} in the rolling operation is larger than the length of the ChunkedArray: {}",
+            window_size,
+            ca.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Centers a trailing-window rolling result by shifting it left `window_size / 2`
+/// positions, so the value computed over `[i - window_size + 1, i]` ends up reported
+/// at (approximately) the middle of that range instead of at its right edge.
+fn center_rolling_result<T>(
+    ca: ChunkedArray<T>,
+    window_size: usize,
+    center: bool,
+) -> Result<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+    T::Native: Copy,
+{
+    if center {
+        ca.shift(-((window_size / 2) as i32), &None)
+    } else {
+        Ok(ca)
+    }
+}
+
+/// Running `(sum, valid_count)` per trailing window of `window_size`, sliding one
+/// element at a time: the entering element is added and, once the window is full, the
+/// leaving element is subtracted.
+fn rolling_sum_and_count<T>(ca: &ChunkedArray<T>, window_size: usize) -> Vec<(T::Native, usize)>
+where
+    T: PolarsNumericType,
+    T::Native: Copy + num::Zero + Add<Output = T::Native> + Sub<Output = T::Native>,
+{
+    let mut out = Vec::with_capacity(ca.len());
+    let mut window: VecDeque<Option<T::Native>> = VecDeque::with_capacity(window_size);
+    let mut sum = T::Native::zero();
+    let mut valid = 0usize;
+    for v in ca.into_iter() {
+        window.push_back(v);
+        if let Some(x) = v {
+            sum = sum + x;
+            valid += 1;
+        }
+        if window.len() > window_size {
+            if let Some(old) = window.pop_front().unwrap() {
+                sum = sum - old;
+                valid -= 1;
+            }
+        }
+        out.push((sum, valid));
+    }
+    out
+}
+
+/// Rolling min (`better = |back, x| back >= x`) or max (`better = |back, x| back <= x`)
+/// via a monotonic deque of `(index, value)`: a new value pops every back-of-deque
+/// entry it makes redundant before being pushed, so the deque stays ordered and its
+/// front is always the current window's extreme.
+fn rolling_extreme<T>(
+    ca: &ChunkedArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    better: impl Fn(T::Native, T::Native) -> bool,
+) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Copy + PartialOrd,
+{
+    let mut builder = PrimitiveChunkedBuilder::<T>::new(ca.name(), ca.len());
+    let mut deque: VecDeque<(usize, T::Native)> = VecDeque::new();
+    let mut valid = 0usize;
+    for (i, v) in ca.into_iter().enumerate() {
+        if i >= window_size {
+            let left_idx = i - window_size;
+            if ca.get(left_idx).is_some() {
+                valid -= 1;
+            }
+            while let Some(&(idx, _)) = deque.front() {
+                if idx <= left_idx {
+                    deque.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+        if let Some(x) = v {
+            valid += 1;
+            while let Some(&(_, back_val)) = deque.back() {
+                if better(back_val, x) {
+                    deque.pop_back();
+                } else {
+                    break;
+                }
+            }
+            deque.push_back((i, x));
+        }
+        if valid >= min_periods {
+            builder.append_option(deque.front().map(|&(_, val)| val));
+        } else {
+            builder.append_null();
+        }
+    }
+    builder.finish()
+}
+
+impl<T> ChunkRollApply<T> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Copy + num::Zero + Add<Output = T::Native> + Sub<Output = T::Native> + PartialOrd + NumCast + ToPrimitive,
+{
+    fn rolling_sum(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>> {
+        rolling_window_guard(self, window_size)?;
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        for (sum, valid) in rolling_sum_and_count(self, window_size) {
+            if valid >= min_periods {
+                builder.append_value(sum);
+            } else {
+                builder.append_null();
+            }
+        }
+        center_rolling_result(builder.finish(), window_size, center)
+    }
+
+    fn rolling_mean(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>> {
+        rolling_window_guard(self, window_size)?;
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
+        for (sum, valid) in rolling_sum_and_count(self, window_size) {
+            if valid >= min_periods && valid > 0 {
+                let mean = sum.to_f64().unwrap() / valid as f64;
+                builder.append_value(NumCast::from(mean).expect("could not cast rolling mean"));
+            } else {
+                builder.append_null();
+            }
+        }
+        center_rolling_result(builder.finish(), window_size, center)
+    }
+
+    fn rolling_min(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>> {
+        rolling_window_guard(self, window_size)?;
+        let ca = rolling_extreme(self, window_size, min_periods, |back, x| back >= x);
+        center_rolling_result(ca, window_size, center)
+    }
+
+    fn rolling_max(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Result<ChunkedArray<T>> {
+        rolling_window_guard(self, window_size)?;
+        let ca = rolling_extreme(self, window_size, min_periods, |back, x| back <= x);
+        center_rolling_result(ca, window_size, center)
+    }
+}
+
 /// Combine 2 ChunkedArrays based on some predicate.
 pub trait ChunkZip<T> {
-    /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
-    /// from `other` where the mask evaluates `false`
+    /// Create a new ChunkedArray with values from self where the mask evaluates `true`,
+    /// values from `other` where the mask evaluates `false`, and a null wherever the mask
+    /// itself is null (`SQL CASE`/`numpy.where` semantics).
     fn zip_with(&self, mask: &BooleanChunked, other: &ChunkedArray<T>) -> Result<ChunkedArray<T>>;
 
-    /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
-    /// from `other` where the mask evaluates `false`
+    /// Create a new ChunkedArray with values from self where the mask evaluates `true`,
+    /// values from `other` where the mask evaluates `false`, and a null wherever the mask
+    /// itself is null (`SQL CASE`/`numpy.where` semantics).
     fn zip_with_series(&self, mask: &BooleanChunked, other: &Series) -> Result<ChunkedArray<T>>;
 }
 
+/// Null-propagating ternary select: a null mask bit emits a null in the output
+/// regardless of the branch values, matching SQL `CASE`/`numpy.where` semantics. `$self`/
+/// `$other` are iterated as `Option<Native>` (not `into_no_null_iter`), so this also
+/// covers `self`/`other` themselves having nulls.
 macro_rules! impl_ternary {
     ($mask:expr, $self:expr, $other:expr, $ty:ty) => {{
-        if $mask.null_count() > 0 {
-            Err(PolarsError::HasNullValues("zip with operation does not support null values in mask (open an issue to prioritize)".into()))
-        } else {
-            let mut val: ChunkedArray<$ty> = $mask
-                .into_no_null_iter()
-                .zip($self)
-                .zip($other)
-                .map(
-                    |((mask_val, true_val), false_val)| {
-                        if mask_val {
-                            true_val
-                        } else {
-                            false_val
-                        }
-                    },
-                )
-                .collect();
-            val.rename($self.name());
-            Ok(val)
-        }
+        let mut val: ChunkedArray<$ty> = $mask
+            .into_iter()
+            .zip($self)
+            .zip($other)
+            .map(|((mask_val, true_val), false_val)| match mask_val {
+                Some(true) => true_val,
+                Some(false) => false_val,
+                None => None,
+            })
+            .collect();
+        val.rename($self.name());
+        Ok(val)
     }};
 }
 macro_rules! impl_ternary_broadcast {
@@ -1096,8 +1736,12 @@ macro_rules! impl_ternary_broadcast {
                 let left = $self.get(0);
                 let right = $other.get(0);
                 let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
-                    .map(|mask_val| if mask_val { left } else { right })
+                    .into_iter()
+                    .map(|mask_val| match mask_val {
+                        Some(true) => left,
+                        Some(false) => right,
+                        None => None,
+                    })
                     .collect();
                 val.rename($self.name());
                 Ok(val)
@@ -1105,9 +1749,13 @@ macro_rules! impl_ternary_broadcast {
             (_, 1) => {
                 let right = $other.get(0);
                 let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
+                    .into_iter()
                     .zip($self)
-                    .map(|(mask_val, left)| if mask_val { left } else { right })
+                    .map(|(mask_val, left)| match mask_val {
+                        Some(true) => left,
+                        Some(false) => right,
+                        None => None,
+                    })
                     .collect();
                 val.rename($self.name());
                 Ok(val)
@@ -1115,16 +1763,19 @@ macro_rules! impl_ternary_broadcast {
             (1, _) => {
                 let left = $self.get(0);
                 let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
+                    .into_iter()
                     .zip($other)
-                    .map(|(mask_val, right)| if mask_val { left } else { right })
+                    .map(|(mask_val, right)| match mask_val {
+                        Some(true) => left,
+                        Some(false) => right,
+                        None => None,
+                    })
                     .collect();
                 val.rename($self.name());
                 Ok(val)
             }
-            (_, _) => Err(PolarsError::ShapeMisMatch(
-                "Shape of parameter `mask` and `other` could not be used in zip_with operation"
-                    .into(),
+            (_, _) => Err(PolarsError::LengthMismatch($self_len, $other_len).context(
+                "Shape of parameter `mask` and `other` could not be used in zip_with operation",
             )),
         }
     }};
@@ -1143,8 +1794,12 @@ where
         if self_len != mask_len || other_len != mask_len {
             impl_ternary_broadcast!(self, self_len, other_len, other, mask, T)
 
-        // cache optimal path
-        } else if self.chunk_id == other.chunk_id && other.chunk_id == mask.chunk_id {
+        // cache optimal path; only valid when the mask itself has no nulls, since
+        // `kernels::zip` has no way to emit a null for a null mask bit
+        } else if self.chunk_id == other.chunk_id
+            && other.chunk_id == mask.chunk_id
+            && mask.null_count() == 0
+        {
             let chunks = self
                 .downcast_chunks()
                 .iter()
@@ -1154,7 +1809,7 @@ where
                 .collect::<Result<Vec<_>>>()?;
             Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
         // no null path
-        } else if self.null_count() == 0 && other.null_count() == 0 {
+        } else if mask.null_count() == 0 && self.null_count() == 0 && other.null_count() == 0 {
             let val: Xob<ChunkedArray<_>> = mask
                 .into_no_null_iter()
                 .zip(self.into_no_null_iter())
@@ -1224,18 +1879,82 @@ impl ChunkZip<Utf8Type> for Utf8Chunked {
 impl ChunkZip<LargeListType> for LargeListChunked {
     fn zip_with(
         &self,
-        _mask: &BooleanChunked,
-        _other: &ChunkedArray<LargeListType>,
+        mask: &BooleanChunked,
+        other: &ChunkedArray<LargeListType>,
     ) -> Result<ChunkedArray<LargeListType>> {
-        unimplemented!()
+        let self_len = self.len();
+        let other_len = other.len();
+        let mask_len = mask.len();
+        let dt = self.get_inner_dtype();
+
+        // A null mask entry propagates a null, matching SQL `CASE`/`numpy.where` semantics
+        // (see `impl_ternary!`).
+        let pick = |mask_val: Option<bool>, left: Option<Series>, right: Option<Series>| {
+            match mask_val {
+                Some(true) => left,
+                Some(false) => right,
+                None => None,
+            }
+        };
+
+        if self_len == mask_len && other_len == mask_len {
+            let mut builder = get_large_list_builder(dt, mask_len, self.name());
+            mask.into_iter()
+                .zip(self.into_iter())
+                .zip(other.into_iter())
+                .for_each(|((mask_val, left), right)| {
+                    builder.append_opt_series(&pick(mask_val, left, right));
+                });
+            Ok(builder.finish())
+        } else {
+            match (self_len, other_len) {
+                (1, 1) => {
+                    let left = self.get(0);
+                    let right = other.get(0);
+                    let mut builder = get_large_list_builder(dt, mask_len, self.name());
+                    mask.into_iter().for_each(|mask_val| {
+                        builder.append_opt_series(&pick(mask_val, left.clone(), right.clone()));
+                    });
+                    Ok(builder.finish())
+                }
+                (_, 1) if self_len == mask_len => {
+                    let right = other.get(0);
+                    let mut builder = get_large_list_builder(dt, mask_len, self.name());
+                    mask.into_iter()
+                        .zip(self.into_iter())
+                        .for_each(|(mask_val, left)| {
+                            builder.append_opt_series(&pick(mask_val, left, right.clone()));
+                        });
+                    Ok(builder.finish())
+                }
+                (1, _) if other_len == mask_len => {
+                    let left = self.get(0);
+                    let mut builder = get_large_list_builder(dt, mask_len, self.name());
+                    mask.into_iter()
+                        .zip(other.into_iter())
+                        .for_each(|(mask_val, right)| {
+                            builder.append_opt_series(&pick(mask_val, left.clone(), right));
+                        });
+                    Ok(builder.finish())
+                }
+                (_, _) => Err(PolarsError::ShapeMisMatch {
+                    expected: (mask_len, mask_len),
+                    got: (self_len, other_len),
+                }
+                .context(
+                    "Shape of parameter `mask` and `other` could not be used in zip_with operation",
+                )),
+            }
+        }
     }
 
     fn zip_with_series(
         &self,
-        _mask: &BooleanChunked,
-        _other: &Series,
+        mask: &BooleanChunked,
+        other: &Series,
     ) -> Result<ChunkedArray<LargeListType>> {
-        unimplemented!()
+        let other = self.unpack_series_matching_type(other)?;
+        self.zip_with(mask, other)
     }
 }
 
@@ -1265,6 +1984,211 @@ pub trait ChunkAggSeries {
     fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
         unimplemented!()
     }
+    /// Get the GCD of all non-null values of the ChunkedArray as a new Series of
+    /// length 1. See [`ChunkGcdLcm::gcd`] for the actual fold.
+    fn gcd_as_series(&self) -> Series {
+        unimplemented!()
+    }
+    /// Get the LCM of all non-null values of the ChunkedArray as a new Series of
+    /// length 1. See [`ChunkGcdLcm::lcm`] for the actual fold.
+    fn lcm_as_series(&self) -> Series {
+        unimplemented!()
+    }
+    /// Get the product of all non-null values of the ChunkedArray as a new Series of
+    /// length 1. See [`ChunkProduct::product`] for the actual fold.
+    fn product_as_series(&self) -> Series {
+        unimplemented!()
+    }
+}
+
+/// The result of folding [`ChunkProduct::product`]: the accumulated value, and whether
+/// it saturated at `T::MAX`/`T::MIN` rather than wrapping. `ChunkProduct` requires
+/// `num::CheckedMul`, so only integer types have a `product`; float types do not.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Product<N> {
+    pub value: N,
+    pub overflowed: bool,
+}
+
+/// Product fold over all non-null values of a numeric `ChunkedArray`.
+///
+/// NOTE: `ChunkAggSeries::product_as_series` is the unit-length-`Series` entry point
+/// this is meant to back, but (as with [`ChunkGcdLcm`]) `ChunkAggSeries` has no impls
+/// in this tree snapshot to hook into, so the fold lives here as a directly-usable
+/// sibling trait in the meantime.
+pub trait ChunkProduct<T> {
+    /// Product of all non-null values, seeded with `1`. Tracks the running product in
+    /// `T`'s own native type via `checked_mul`, so a product that's still exactly
+    /// representable in `T` -- even well past `f64`'s 53-bit mantissa for `Int64`/
+    /// `UInt64` -- comes back exact instead of losing precision through a float
+    /// round-trip. Saturates at `T::MAX`/`T::MIN` (matching the sign of the overflow)
+    /// on genuine overflow rather than wrapping silently. `None` on an empty/all-null
+    /// array.
+    fn product(&self) -> Option<Product<T>>;
+}
+
+impl<T> ChunkProduct<T::Native> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Copy + num::One + num::Bounded + NumCast + ToPrimitive + num::CheckedMul,
+{
+    fn product(&self) -> Option<Product<T::Native>> {
+        let mut acc = T::Native::one();
+        let mut seen = false;
+        let mut overflowed = false;
+        for v in self.into_iter().flatten() {
+            seen = true;
+            if overflowed {
+                continue;
+            }
+            acc = match acc.checked_mul(&v) {
+                Some(prod) => prod,
+                None => {
+                    overflowed = true;
+                    if acc.to_f64().unwrap() * v.to_f64().unwrap() < 0.0 {
+                        T::Native::min_value()
+                    } else {
+                        T::Native::max_value()
+                    }
+                }
+            };
+        }
+        if seen {
+            Some(Product {
+                value: acc,
+                overflowed,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// The result of folding [`ChunkGcdLcm::lcm`]: the accumulated value, and whether it
+/// hit `T::MAX` and saturated rather than overflowing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lcm<N> {
+    pub value: N,
+    pub saturated: bool,
+}
+
+/// GCD/LCM folds over all non-null values of an integer `ChunkedArray`.
+///
+/// NOTE: `ChunkAggSeries::gcd_as_series`/`lcm_as_series` are the unit-length-`Series`
+/// entry points this is meant to back, but `ChunkAggSeries` has no impls in this tree
+/// snapshot to hook into (its defining file isn't part of it), so the fold lives here
+/// as a directly-usable sibling trait in the meantime.
+pub trait ChunkGcdLcm<T> {
+    /// GCD of all non-null values via the binary Euclidean algorithm
+    /// (`a, b = b, a % b` until `b == 0`), seeded with `0` (`gcd(0, x) == x`). `None`
+    /// on an empty/all-null array.
+    fn gcd(&self) -> Option<T>;
+
+    /// LCM of all non-null values, seeded with `1` and computed as
+    /// `lcm = lcm / gcd(lcm, x) * x` (dividing before multiplying to limit overflow).
+    /// Saturates at `T::MAX` instead of wrapping on overflow; check
+    /// [`Lcm::saturated`] to see whether that happened. `None` on an empty/all-null
+    /// array.
+    fn lcm(&self) -> Option<Lcm<T>>;
+}
+
+/// Normalizes `binary_gcd`'s result to the conventional non-negative GCD/LCM. Only
+/// signed integer types can actually produce a negative remainder (Rust's `%` follows
+/// the sign of the dividend), so this is a real `.abs()` for those and a no-op for
+/// unsigned types, which are already non-negative.
+trait GcdAbs: Copy {
+    fn gcd_abs(self) -> Self;
+}
+
+macro_rules! impl_gcd_abs_signed {
+    ($($t:ty),*) => {
+        $(impl GcdAbs for $t {
+            #[inline]
+            fn gcd_abs(self) -> Self {
+                self.abs()
+            }
+        })*
+    };
+}
+macro_rules! impl_gcd_abs_unsigned {
+    ($($t:ty),*) => {
+        $(impl GcdAbs for $t {
+            #[inline]
+            fn gcd_abs(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+impl_gcd_abs_signed!(i8, i16, i32, i64, i128, isize);
+impl_gcd_abs_unsigned!(u8, u16, u32, u64, u128, usize);
+
+fn binary_gcd<N>(mut a: N, mut b: N) -> N
+where
+    N: Copy + num::Zero + std::ops::Rem<Output = N> + GcdAbs,
+{
+    while !b.is_zero() {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a.gcd_abs()
+}
+
+impl<T> ChunkGcdLcm<T::Native> for ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: num::Zero
+        + num::One
+        + num::Bounded
+        + num::CheckedMul
+        + std::ops::Rem<Output = T::Native>
+        + std::ops::Div<Output = T::Native>
+        + GcdAbs,
+{
+    fn gcd(&self) -> Option<T::Native> {
+        let mut acc = T::Native::zero();
+        let mut seen = false;
+        for v in self.into_iter().flatten() {
+            seen = true;
+            acc = binary_gcd(acc, v);
+        }
+        if seen {
+            Some(acc)
+        } else {
+            None
+        }
+    }
+
+    fn lcm(&self) -> Option<Lcm<T::Native>> {
+        let mut acc = T::Native::one();
+        let mut seen = false;
+        let mut saturated = false;
+        for v in self.into_iter().flatten() {
+            seen = true;
+            if saturated {
+                continue;
+            }
+            let v = v.gcd_abs();
+            let g = binary_gcd(acc, v);
+            let reduced = acc / g;
+            acc = match reduced.checked_mul(&v) {
+                Some(prod) => prod,
+                None => {
+                    saturated = true;
+                    T::Native::max_value()
+                }
+            };
+        }
+        if seen {
+            Some(Lcm {
+                value: acc,
+                saturated,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1294,17 +2218,17 @@ mod test {
     fn test_fill_none() {
         let ca =
             Int32Chunked::new_from_opt_slice("", &[None, Some(2), Some(3), None, Some(4), None]);
-        let filled = ca.fill_none(FillNoneStrategy::Forward).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Forward, None).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[None, Some(2), Some(3), Some(3), Some(4), Some(4)]
         );
-        let filled = ca.fill_none(FillNoneStrategy::Backward).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Backward, None).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[Some(2), Some(2), Some(3), Some(4), Some(4), None]
         );
-        let filled = ca.fill_none(FillNoneStrategy::Min).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Min, None).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[Some(2), Some(2), Some(3), Some(2), Some(4), Some(2)]
@@ -1314,11 +2238,236 @@ mod test {
             Vec::from(&filled),
             &[Some(10), Some(2), Some(3), Some(10), Some(4), Some(10)]
         );
-        let filled = ca.fill_none(FillNoneStrategy::Mean).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Mean, None).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[Some(3), Some(2), Some(3), Some(3), Some(4), Some(3)]
         );
         println!("{:?}", filled);
     }
+
+    #[test]
+    fn test_fill_none_limit() {
+        let ca = Int32Chunked::new_from_opt_slice(
+            "",
+            &[Some(1), None, None, None, Some(5)],
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Forward, Some(1)).unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), Some(1), None, None, Some(5)]
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Backward, Some(2)).unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), None, Some(5), Some(5), Some(5)]
+        );
+    }
+
+    #[test]
+    fn test_fill_none_linear() {
+        let ca = Int32Chunked::new_from_opt_slice(
+            "",
+            &[None, Some(0), None, None, Some(9), None],
+        );
+        let filled = ca
+            .fill_none(FillNoneStrategy::Linear { extrapolate: false }, None)
+            .unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[None, Some(0), Some(3), Some(6), Some(9), None]
+        );
+
+        let filled = ca
+            .fill_none(FillNoneStrategy::Linear { extrapolate: true }, None)
+            .unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(0), Some(0), Some(3), Some(6), Some(9), Some(9)]
+        );
+
+        // The interior gap (indices 2, 3) is 2 nulls long; with `limit = Some(1)` only the
+        // first one gets interpolated and the rest of the run stays null, the same
+        // partial-fill-then-stop behavior `Forward`/`Backward` give an over-limit run.
+        let filled = ca
+            .fill_none(FillNoneStrategy::Linear { extrapolate: false }, Some(1))
+            .unwrap();
+        assert_eq!(
+            Vec::from(&filled),
+            &[None, Some(0), Some(3), None, Some(9), None]
+        );
+    }
+
+    #[test]
+    fn test_cum_agg() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(1), None, Some(2), Some(3), None]);
+        assert_eq!(
+            Vec::from(&ca.cumsum()),
+            &[Some(1), None, Some(3), Some(6), None]
+        );
+        assert_eq!(
+            Vec::from(&ca.cumprod()),
+            &[Some(1), None, Some(2), Some(6), None]
+        );
+        assert_eq!(
+            Vec::from(&ca.cummin()),
+            &[Some(1), None, Some(1), Some(1), None]
+        );
+        assert_eq!(
+            Vec::from(&ca.cummax()),
+            &[Some(1), None, Some(2), Some(3), None]
+        );
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(4), None, Some(6), Some(8)]);
+        assert_eq!(ca.gcd(), Some(2));
+        let lcm = ca.lcm().unwrap();
+        assert_eq!(lcm.value, 24);
+        assert!(!lcm.saturated);
+
+        let empty = Int32Chunked::new_from_opt_slice("", &[None, None]);
+        assert_eq!(empty.gcd(), None);
+        assert!(empty.lcm().is_none());
+
+        let ca = Int32Chunked::new_from_slice("", &[i32::MAX, i32::MAX - 1]);
+        let lcm = ca.lcm().unwrap();
+        assert!(lcm.saturated);
+        assert_eq!(lcm.value, i32::MAX);
+
+        // A negative input shouldn't flip the sign of the result: GCD/LCM are always
+        // non-negative by convention.
+        let ca = Int32Chunked::new_from_slice("", &[-6, 4]);
+        assert_eq!(ca.gcd(), Some(2));
+        assert_eq!(ca.lcm().unwrap().value, 12);
+    }
+
+    #[test]
+    fn test_product() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(2), None, Some(3), Some(4)]);
+        let product = ca.product().unwrap();
+        assert_eq!(product.value, 24);
+        assert!(!product.overflowed);
+
+        let empty = Int32Chunked::new_from_opt_slice("", &[None, None]);
+        assert!(empty.product().is_none());
+
+        let ca = Int32Chunked::new_from_slice("", &[i32::MAX, 2]);
+        let product = ca.product().unwrap();
+        assert!(product.overflowed);
+        assert_eq!(product.value, i32::MAX);
+
+        let ca = Int32Chunked::new_from_slice("", &[i32::MIN, 2]);
+        let product = ca.product().unwrap();
+        assert!(product.overflowed);
+        assert_eq!(product.value, i32::MIN);
+    }
+
+    #[test]
+    fn test_product_i64_exact_past_f64_mantissa() {
+        // 1234567891 * 1234567897 == 1524157884895595227, still well within i64's range
+        // but past f64's 53-bit mantissa -- a float round-trip would silently return
+        // 1524157884895595264 instead.
+        let ca = Int64Chunked::new_from_slice("", &[1234567891i64, 1234567897]);
+        let product = ca.product().unwrap();
+        assert!(!product.overflowed);
+        assert_eq!(product.value, 1524157884895595227);
+    }
+
+    #[test]
+    fn test_sort_with_nulls() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(3), None, Some(1), Some(2), None]);
+        assert_eq!(
+            Vec::from(&ca.sort(false, false)),
+            &[None, None, Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(&ca.sort(false, true)),
+            &[Some(1), Some(2), Some(3), None, None]
+        );
+        assert_eq!(
+            Vec::from(&ca.sort(true, true)),
+            &[Some(3), Some(2), Some(1), None, None]
+        );
+    }
+
+    #[test]
+    fn test_sort_in_place_no_nulls() {
+        let mut ca = Int32Chunked::new_from_slice("", &[3, 1, 2]);
+        ca.sort_in_place(false, false);
+        assert_eq!(ca.cont_slice().unwrap(), &[1, 2, 3]);
+        ca.sort_in_place(true, false);
+        assert_eq!(ca.cont_slice().unwrap(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn test_argsort_with_nulls() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(30), None, Some(10), Some(20)]);
+        assert_eq!(ca.argsort(false, false), vec![1, 2, 3, 0]);
+        assert_eq!(ca.argsort(false, true), vec![2, 3, 0, 1]);
+    }
+
+    #[test]
+    fn test_top_k_bottom_k() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(3), None, Some(1), Some(4), Some(2)]);
+        assert_eq!(Vec::from(&ca.top_k(2)), &[Some(4), Some(3)]);
+        assert_eq!(Vec::from(&ca.bottom_k(2)), &[Some(1), Some(2)]);
+        // fewer than k non-null values: just return all of them
+        assert_eq!(
+            Vec::from(&ca.top_k(100)),
+            &[Some(4), Some(3), Some(2), Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_rolling_sum_mean_min_max() {
+        let ca = Int32Chunked::new_from_opt_slice("", &[Some(1), Some(2), None, Some(4), Some(5)]);
+
+        let sum = ca.rolling_sum(3, 2, false).unwrap();
+        assert_eq!(
+            Vec::from(&sum),
+            &[None, Some(3), Some(3), Some(6), Some(9)]
+        );
+
+        let mean = ca.rolling_mean(3, 2, false).unwrap();
+        assert_eq!(mean.get(4), Some(4)); // (4+5)/2 truncated via NumCast<i32>
+
+        let min = ca.rolling_min(3, 1, false).unwrap();
+        assert_eq!(Vec::from(&min), &[Some(1), Some(1), Some(1), Some(2), Some(4)]);
+
+        let max = ca.rolling_max(3, 1, false).unwrap();
+        assert_eq!(Vec::from(&max), &[Some(1), Some(2), Some(2), Some(4), Some(5)]);
+
+        assert!(ca.rolling_sum(10, 1, false).is_err());
+    }
+
+    #[test]
+    fn test_rolling_sum_centered() {
+        let ca = Int32Chunked::new_from_slice("", &[1, 2, 3, 4, 5]);
+        let sum = ca.rolling_sum(3, 1, true).unwrap();
+        // Centering shifts the trailing-window result left by `window_size / 2`, so
+        // position `i` reports the window `[i-1, i+1]`; the last position has nothing
+        // to shift in and comes out null rather than a recomputed partial window.
+        assert_eq!(
+            Vec::from(&sum),
+            &[Some(3), Some(6), Some(9), Some(12), None]
+        );
+    }
+
+    #[test]
+    fn test_zip_with_null_mask() {
+        let a = Int32Chunked::new_from_slice("", &[1, 2, 3, 4]);
+        let b = Int32Chunked::new_from_slice("", &[10, 20, 30, 40]);
+        let mask =
+            BooleanChunked::new_from_opt_slice("", &[Some(true), Some(false), None, Some(true)]);
+        let zipped = a.zip_with(&mask, &b).unwrap();
+        assert_eq!(Vec::from(&zipped), &[Some(1), Some(20), None, Some(4)]);
+
+        let a = BooleanChunked::new_from_slice("", &[true, true, false]);
+        let b = BooleanChunked::new_from_slice("", &[false, false, true]);
+        let mask = BooleanChunked::new_from_opt_slice("", &[None, Some(true), Some(false)]);
+        let zipped = a.zip_with(&mask, &b).unwrap();
+        assert_eq!(Vec::from(&zipped), &[None, Some(true), Some(true)]);
+    }
 }
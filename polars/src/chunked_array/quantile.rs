@@ -0,0 +1,151 @@
+//! Quickselect-based `median`/`quantile`, selecting order statistics in expected O(n)
+//! instead of paying for a full O(n log n) sort.
+//!
+//! NOTE: `ChunkAgg::median`/`ChunkAgg::quantile` (`ChunkAgg` is used in `ops.rs` by
+//! `ChunkFillNone::fill_none`'s `FillNoneStrategy::Mean`/`Min`/`Max` arms, e.g. via
+//! `self.mean()`) are implemented somewhere that isn't part of this tree snapshot, so
+//! this can't be wired in as a fast path for that impl directly. `ChunkQuantile` is a
+//! sibling trait exposing the same semantics (`None` on empty/all-null input) so callers
+//! have the fast path today; folding it into `ChunkAgg` is future work once that impl
+//! exists.
+use crate::prelude::*;
+use num::{NumCast, ToPrimitive};
+
+/// Dutch-national-flag partition of `values` around `pivot`: `< pivot`, `== pivot`,
+/// `> pivot`. Returns the `[start, end)` range covered by the `== pivot` middle segment.
+/// Grouping every pivot-equal element in one pass (rather than a plain 2-way partition)
+/// is what guarantees quickselect terminates on all-equal input instead of repeatedly
+/// re-selecting the same pivot.
+fn three_way_partition<T: PartialOrd + Copy>(values: &mut [T], pivot: T) -> (usize, usize) {
+    let mut lt = 0;
+    let mut i = 0;
+    let mut gt = values.len();
+    while i < gt {
+        if values[i] < pivot {
+            values.swap(lt, i);
+            lt += 1;
+            i += 1;
+        } else if values[i] > pivot {
+            gt -= 1;
+            values.swap(i, gt);
+        } else {
+            i += 1;
+        }
+    }
+    (lt, gt)
+}
+
+/// Hoare selection (quickselect): the `k`-th smallest element (0-indexed) of `values`,
+/// in expected O(n). `values` is reordered in the process.
+fn quickselect<T: PartialOrd + Copy>(values: &mut [T], k: usize) -> T {
+    let mut lo = 0;
+    let mut hi = values.len();
+    loop {
+        if hi - lo == 1 {
+            return values[lo];
+        }
+        let pivot = values[lo + (hi - lo) / 2];
+        let (eq_start, eq_end) = {
+            let (a, b) = three_way_partition(&mut values[lo..hi], pivot);
+            (lo + a, lo + b)
+        };
+        if k < eq_start {
+            hi = eq_start;
+        } else if k < eq_end {
+            return pivot;
+        } else {
+            lo = eq_end;
+        }
+    }
+}
+
+pub trait ChunkQuantile<T> {
+    /// The median: the average of the two middle order statistics for even-length
+    /// input, the single middle one for odd-length. `None` on empty/all-null input.
+    fn median(&self) -> Option<T>;
+
+    /// The order statistic at fractional rank `quantile * (n - 1)` (`quantile` in
+    /// `0.0..=1.0`), linearly interpolated between the two bracketing integer ranks.
+    /// `None` on empty/all-null input.
+    fn quantile(&self, quantile: f64) -> Result<Option<T>>;
+}
+
+impl<T> ChunkQuantile<T::Native> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd + NumCast + ToPrimitive,
+{
+    fn median(&self) -> Option<T::Native> {
+        self.quantile(0.5).unwrap_or(None)
+    }
+
+    fn quantile(&self, quantile: f64) -> Result<Option<T::Native>> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsError::InvalidOperation(
+                format!("quantile should be between 0.0 and 1.0, got {}", quantile).into(),
+            ));
+        }
+        let mut values: Vec<T::Native> = self.into_iter().flatten().collect();
+        if values.is_empty() {
+            return Ok(None);
+        }
+
+        let n = values.len();
+        let rank = quantile * (n - 1) as f64;
+        let lo = rank.floor() as usize;
+        let hi = rank.ceil() as usize;
+        let frac = rank - lo as f64;
+
+        let lo_val = quickselect(&mut values, lo);
+        let out = if hi == lo {
+            lo_val
+        } else {
+            let hi_val = quickselect(&mut values, hi);
+            let lo_f = lo_val.to_f64().unwrap();
+            let hi_f = hi_val.to_f64().unwrap();
+            NumCast::from(lo_f + (hi_f - lo_f) * frac).expect("could not cast quantile result")
+        };
+        Ok(Some(out))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn median_odd_and_even() {
+        let ca = Int32Chunked::new_from_slice("", &[5, 1, 4, 2, 3]);
+        assert_eq!(ca.median(), Some(3));
+
+        let ca = Int32Chunked::new_from_slice("", &[1, 2, 3, 4]);
+        assert_eq!(ca.median(), Some(2)); // (2+3)/2 truncated via NumCast<i32>
+    }
+
+    #[test]
+    fn quantile_interpolates() {
+        let ca = Float64Chunked::new_from_slice("", &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(ca.quantile(0.0).unwrap(), Some(1.0));
+        assert_eq!(ca.quantile(1.0).unwrap(), Some(4.0));
+        assert_eq!(ca.quantile(0.5).unwrap(), Some(2.5));
+    }
+
+    #[test]
+    fn quantile_handles_all_equal_and_empty() {
+        let ca = Int32Chunked::new_from_slice("", &[7, 7, 7, 7]);
+        assert_eq!(ca.median(), Some(7));
+
+        let ca = Int32Chunked::new_from_opt_slice("", &[None, None]);
+        assert_eq!(ca.median(), None);
+        assert_eq!(ca.quantile(0.5).unwrap(), None);
+    }
+
+    #[test]
+    fn quantile_rejects_out_of_range() {
+        let ca = Int32Chunked::new_from_slice("", &[1, 2, 3]);
+        assert!(matches!(
+            ca.quantile(1.5),
+            Err(PolarsError::InvalidOperation(_))
+        ));
+    }
+}
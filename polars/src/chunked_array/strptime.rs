@@ -0,0 +1,101 @@
+//! Parse `Utf8Chunked` into temporal series using a user-supplied chrono format string.
+//!
+//! NOTE: this needs a `mod strptime;` added to `chunked_array/mod.rs` to be compiled in;
+//! that file isn't part of this tree snapshot.
+#[cfg(feature = "temporal")]
+use crate::prelude::*;
+
+#[cfg(feature = "temporal")]
+impl Utf8Chunked {
+    /// Parse each string with `fmt` (e.g. `"%Y-%m-%d %H:%M:%S"` or `"%FT%H:%M:%S"`) into a
+    /// timestamp series in the given `unit`. Null and unparseable entries become null.
+    pub fn as_datetime(&self, fmt: &str, unit: TimeUnit) -> Series {
+        macro_rules! parse_into {
+            ($t:ty, $variant:ident, $to_epoch:expr) => {{
+                let mut builder = PrimitiveChunkedBuilder::<$t>::new(self.name(), self.len());
+                for opt_s in self {
+                    match opt_s.and_then(|s| chrono::NaiveDateTime::parse_from_str(s, fmt).ok()) {
+                        Some(ndt) => builder.append_value($to_epoch(ndt)),
+                        None => builder.append_null(),
+                    }
+                }
+                Series::$variant(builder.finish())
+            }};
+        }
+
+        match unit {
+            TimeUnit::Nanosecond => parse_into!(
+                TimestampNanosecondType,
+                TimestampNanosecond,
+                |ndt: chrono::NaiveDateTime| ndt.timestamp_nanos()
+            ),
+            TimeUnit::Microsecond => parse_into!(
+                TimestampMicrosecondType,
+                TimestampMicrosecond,
+                |ndt: chrono::NaiveDateTime| ndt.timestamp() * 1_000_000
+                    + ndt.timestamp_subsec_micros() as i64
+            ),
+            TimeUnit::Millisecond => parse_into!(
+                TimestampMillisecondType,
+                TimestampMillisecond,
+                |ndt: chrono::NaiveDateTime| ndt.timestamp_millis()
+            ),
+            TimeUnit::Second => parse_into!(
+                TimestampSecondType,
+                TimestampSecond,
+                |ndt: chrono::NaiveDateTime| ndt.timestamp()
+            ),
+        }
+    }
+
+    /// Parse each string with `fmt` into a `Date32` series (days since the epoch). Null
+    /// and unparseable entries become null.
+    pub fn as_date(&self, fmt: &str) -> Series {
+        let epoch = chrono::NaiveDate::from_ymd(1970, 1, 1);
+        let mut builder = PrimitiveChunkedBuilder::<Date32Type>::new(self.name(), self.len());
+        for opt_s in self {
+            match opt_s.and_then(|s| chrono::NaiveDate::parse_from_str(s, fmt).ok()) {
+                Some(date) => {
+                    builder.append_value(date.signed_duration_since(epoch).num_days() as i32)
+                }
+                None => builder.append_null(),
+            }
+        }
+        Series::Date32(builder.finish())
+    }
+}
+
+#[cfg(all(test, feature = "temporal"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn parse_date() {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "dates",
+            &[Some("2021-03-04"), None, Some("not a date")],
+        );
+        let s = ca.as_date("%Y-%m-%d");
+        assert_eq!(
+            r#"Series: date32(day)
+[
+	2021-03-04
+	null
+	null
+]"#,
+            format!("{:?}", s)
+        );
+    }
+
+    #[test]
+    fn parse_datetime() {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "ts",
+            &[Some("2021-03-04 10:30:00"), None, Some("garbage")],
+        );
+        let s = ca.as_datetime("%Y-%m-%d %H:%M:%S", TimeUnit::Second);
+        let out = format!("{:?}", s);
+        assert!(out.contains("timestamp(s)"));
+        assert!(out.contains("null"));
+    }
+}
@@ -3,49 +3,93 @@
 //! IntoTakeRandom provides structs that implement the TakeRandom trait.
 //! There are several structs that implement the fastest path for random access.
 //!
-use crate::chunked_array::builder::{PrimitiveChunkedBuilder, Utf8ChunkedBuilder};
+use crate::chunked_array::builder::{
+    get_large_list_builder, PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
+};
 use crate::prelude::*;
 use arrow::array::{
     Array, BooleanArray, LargeListArray, PrimitiveArray, PrimitiveBuilder, StringArray,
 };
 
+/// An index iterator that knows its exact remaining length, so take builders can
+/// preallocate precisely instead of guessing from `size_hint().0`.
+pub trait TakeIterator: Iterator<Item = usize> + ExactSizeIterator + DoubleEndedIterator {}
+impl<I: Iterator<Item = usize> + ExactSizeIterator + DoubleEndedIterator> TakeIterator for I {}
+
+/// Same as [`TakeIterator`] but for the nullable (`Option<usize>`) index iterators used
+/// by the `_opt` take variants.
+pub trait TakeIteratorNulls:
+    Iterator<Item = Option<usize>> + ExactSizeIterator + DoubleEndedIterator
+{
+}
+impl<I: Iterator<Item = Option<usize>> + ExactSizeIterator + DoubleEndedIterator> TakeIteratorNulls
+    for I
+{
+}
+
 pub trait Take {
     /// Take values from ChunkedArray by index.
-    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Result<Self>
+    fn take(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self>
     where
         Self: std::marker::Sized;
 
     /// Take values from ChunkedArray by index without checking bounds.
-    unsafe fn take_unchecked(
-        &self,
-        indices: impl Iterator<Item = usize>,
-        capacity: Option<usize>,
-    ) -> Self
+    unsafe fn take_unchecked(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Self
     where
         Self: std::marker::Sized;
 
     /// Take values from ChunkedArray by Option<index>.
-    fn take_opt(
-        &self,
-        indices: impl Iterator<Item = Option<usize>>,
-        capacity: Option<usize>,
-    ) -> Result<Self>
+    fn take_opt(&self, indices: impl TakeIteratorNulls, capacity: Option<usize>) -> Result<Self>
     where
         Self: std::marker::Sized;
 
     /// Take values from ChunkedArray by Option<index>.
     unsafe fn take_opt_unchecked(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Self
     where
         Self: std::marker::Sized;
+
+    /// Take values from ChunkedArray by index, gathering from the back of `indices`
+    /// instead of the front.
+    fn take_rev(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        self.take(indices.rev(), capacity)
+    }
+
+    /// Gather the last `n` entries of `indices`, in their original order, without
+    /// materializing a reversed `Vec` of the full index set first.
+    fn take_last(&self, indices: impl TakeIterator, n: usize) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        self.take(indices.rev().take(n).rev(), Some(n))
+    }
+
+    /// Like [`take`](Take::take), but with a caller-provided guarantee that `indices` is
+    /// sorted in non-decreasing order (e.g. the output of a previous sort, or `0..n`).
+    /// Implementations may use this to walk a [`MonotonicCursor`] instead of doing a
+    /// fresh chunk-boundary lookup per element, turning an O(log n) (or worse) lookup
+    /// per index into an amortized O(1) cursor advance. Passing indices that are not
+    /// actually sorted does not panic, it just silently gathers the wrong rows, so only
+    /// use this when the index source genuinely guarantees the ordering.
+    ///
+    /// The default falls back to the unordered [`take`](Take::take).
+    fn take_sorted(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        self.take(indices, capacity)
+    }
 }
 
 macro_rules! impl_take {
     ($self:ident, $indices:ident, $capacity:ident, $builder:ident) => {{
-        let capacity = $capacity.unwrap_or($indices.size_hint().0);
+        let capacity = $capacity.unwrap_or($indices.len());
         let mut builder = $builder::new($self.name(), capacity);
 
         let taker = $self.take_rand();
@@ -61,7 +105,7 @@ macro_rules! impl_take {
 
 macro_rules! impl_take_opt {
     ($self:ident, $indices:ident, $capacity:ident, $builder:ident) => {{
-        let capacity = $capacity.unwrap_or($indices.size_hint().0);
+        let capacity = $capacity.unwrap_or($indices.len());
         let mut builder = $builder::new($self.name(), capacity);
         let taker = $self.take_rand();
 
@@ -80,7 +124,7 @@ macro_rules! impl_take_opt {
 
 macro_rules! impl_take_opt_unchecked {
     ($self:ident, $indices:ident, $capacity:ident, $builder:ident) => {{
-        let capacity = $capacity.unwrap_or($indices.size_hint().0);
+        let capacity = $capacity.unwrap_or($indices.len());
         let mut builder = $builder::new($self.name(), capacity);
         let taker = $self.take_rand();
 
@@ -99,7 +143,7 @@ macro_rules! impl_take_opt_unchecked {
 
 macro_rules! impl_take_unchecked {
     ($self:ident, $indices:ident, $capacity:ident, $builder:ident) => {{
-        let capacity = $capacity.unwrap_or($indices.size_hint().0);
+        let capacity = $capacity.unwrap_or($indices.len());
         let mut builder = $builder::new($self.name(), capacity);
 
         let taker = $self.take_rand();
@@ -111,17 +155,49 @@ macro_rules! impl_take_unchecked {
     }};
 }
 
+/// Like `impl_take!`, but for the multi-chunk case it builds its own `$StructMany`
+/// (with a precomputed `ChunkOffsets`) and drives it through `gather_with_offsets`
+/// instead of going through `take_rand`'s boxed dispatch, so a non-decreasing `$indices`
+/// only ever walks a single forward `MonotonicCursor`.
+macro_rules! impl_take_sorted {
+    ($self:ident, $indices:ident, $capacity:ident, $builder:ident, $StructMany:ident) => {{
+        let capacity = $capacity.unwrap_or($indices.len());
+        let mut builder = $builder::new($self.name(), capacity);
+        let chunks = $self.downcast_chunks();
+
+        if chunks.len() <= 1 {
+            let taker = $self.take_rand();
+            for idx in $indices {
+                match taker.get(idx) {
+                    Some(v) => builder.append_value(v),
+                    None => builder.append_null(),
+                }
+            }
+        } else {
+            let offsets = ChunkOffsets::new(chunks.iter().map(|a| a.len()));
+            let many = $StructMany { chunks, offsets };
+            for v in many.gather_with_offsets($indices) {
+                match v {
+                    Some(v) => builder.append_value(v),
+                    None => builder.append_null(),
+                }
+            }
+        }
+        Ok(builder.finish())
+    }};
+}
+
 impl<T> Take for ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
-    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Result<Self> {
+    fn take(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self> {
         impl_take!(self, indices, capacity, PrimitiveChunkedBuilder)
     }
 
     unsafe fn take_unchecked(
         &self,
-        indices: impl Iterator<Item = usize>,
+        indices: impl TakeIterator,
         capacity: Option<usize>,
     ) -> Self {
         impl_take_unchecked!(self, indices, capacity, PrimitiveChunkedBuilder)
@@ -129,7 +205,7 @@ where
 
     fn take_opt(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Result<Self> {
         impl_take_opt!(self, indices, capacity, PrimitiveChunkedBuilder)
@@ -137,15 +213,19 @@ where
 
     unsafe fn take_opt_unchecked(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Self {
         impl_take_opt_unchecked!(self, indices, capacity, PrimitiveChunkedBuilder)
     }
+
+    fn take_sorted(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self> {
+        impl_take_sorted!(self, indices, capacity, PrimitiveChunkedBuilder, NumTakeRandomChunked)
+    }
 }
 
 impl Take for BooleanChunked {
-    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Result<Self>
+    fn take(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
@@ -154,7 +234,7 @@ impl Take for BooleanChunked {
 
     unsafe fn take_unchecked(
         &self,
-        indices: impl Iterator<Item = usize>,
+        indices: impl TakeIterator,
         capacity: Option<usize>,
     ) -> Self {
         impl_take_unchecked!(self, indices, capacity, PrimitiveChunkedBuilder)
@@ -162,7 +242,7 @@ impl Take for BooleanChunked {
 
     fn take_opt(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Result<Self> {
         impl_take_opt!(self, indices, capacity, PrimitiveChunkedBuilder)
@@ -170,15 +250,19 @@ impl Take for BooleanChunked {
 
     unsafe fn take_opt_unchecked(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Self {
         impl_take_opt_unchecked!(self, indices, capacity, PrimitiveChunkedBuilder)
     }
+
+    fn take_sorted(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self> {
+        impl_take_sorted!(self, indices, capacity, PrimitiveChunkedBuilder, BoolTakeRandom)
+    }
 }
 
 impl Take for Utf8Chunked {
-    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Result<Self>
+    fn take(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
@@ -187,7 +271,7 @@ impl Take for Utf8Chunked {
 
     unsafe fn take_unchecked(
         &self,
-        indices: impl Iterator<Item = usize>,
+        indices: impl TakeIterator,
         capacity: Option<usize>,
     ) -> Self {
         impl_take_unchecked!(self, indices, capacity, Utf8ChunkedBuilder)
@@ -195,7 +279,7 @@ impl Take for Utf8Chunked {
 
     fn take_opt(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Result<Self>
     where
@@ -206,205 +290,153 @@ impl Take for Utf8Chunked {
 
     unsafe fn take_opt_unchecked(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Self {
         impl_take_opt_unchecked!(self, indices, capacity, Utf8ChunkedBuilder)
     }
-}
 
-// TODO: Use nested macro to clean this mess up a bit.
-// TODO: Utf8 largelist take
-
-macro_rules! impl_list_take_apply_macro {
-    ($self:ident, $impl_list_take:ident) => {{
-        match $self.dtype() {
-            ArrowDataType::LargeList(dt) => match **dt {
-                ArrowDataType::Utf8 => todo!(),
-                ArrowDataType::Boolean => $impl_list_take!(BooleanType),
-                ArrowDataType::UInt8 => $impl_list_take!(UInt8Type),
-                ArrowDataType::UInt16 => $impl_list_take!(UInt16Type),
-                ArrowDataType::UInt32 => $impl_list_take!(UInt32Type),
-                ArrowDataType::UInt64 => $impl_list_take!(UInt64Type),
-                ArrowDataType::Int8 => $impl_list_take!(Int8Type),
-                ArrowDataType::Int16 => $impl_list_take!(Int16Type),
-                ArrowDataType::Int32 => $impl_list_take!(Int32Type),
-                ArrowDataType::Int64 => $impl_list_take!(Int64Type),
-                ArrowDataType::Float32 => $impl_list_take!(Float32Type),
-                ArrowDataType::Float64 => $impl_list_take!(Float64Type),
-                ArrowDataType::Date32(DateUnit::Day) => $impl_list_take!(Date32Type),
-                ArrowDataType::Date64(DateUnit::Millisecond) => $impl_list_take!(Date64Type),
-                ArrowDataType::Time32(TimeUnit::Millisecond) => {
-                    $impl_list_take!(Time32MillisecondType)
-                }
-                ArrowDataType::Time32(TimeUnit::Second) => $impl_list_take!(Time32SecondType),
-                ArrowDataType::Time64(TimeUnit::Nanosecond) => {
-                    $impl_list_take!(Time64NanosecondType)
-                }
-                ArrowDataType::Time64(TimeUnit::Microsecond) => {
-                    $impl_list_take!(Time64MicrosecondType)
-                }
-                ArrowDataType::Interval(IntervalUnit::DayTime) => {
-                    $impl_list_take!(IntervalDayTimeType)
-                }
-                ArrowDataType::Interval(IntervalUnit::YearMonth) => {
-                    $impl_list_take!(IntervalYearMonthType)
-                }
-                ArrowDataType::Duration(TimeUnit::Nanosecond) => {
-                    $impl_list_take!(DurationNanosecondType)
-                }
-                ArrowDataType::Duration(TimeUnit::Microsecond) => {
-                    $impl_list_take!(DurationMicrosecondType)
-                }
-                ArrowDataType::Duration(TimeUnit::Millisecond) => {
-                    $impl_list_take!(DurationMillisecondType)
-                }
-                ArrowDataType::Duration(TimeUnit::Second) => $impl_list_take!(DurationSecondType),
-                ArrowDataType::Timestamp(TimeUnit::Nanosecond, _) => {
-                    $impl_list_take!(TimestampNanosecondType)
-                }
-                ArrowDataType::Timestamp(TimeUnit::Microsecond, _) => {
-                    $impl_list_take!(TimestampMicrosecondType)
-                }
-                ArrowDataType::Timestamp(TimeUnit::Millisecond, _) => {
-                    $impl_list_take!(Time32MillisecondType)
-                }
-                ArrowDataType::Timestamp(TimeUnit::Second, _) => {
-                    $impl_list_take!(TimestampSecondType)
-                }
-                _ => unimplemented!(),
-            },
-            _ => unimplemented!(),
-        }
-    }};
+    fn take_sorted(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self> {
+        impl_take_sorted!(self, indices, capacity, Utf8ChunkedBuilder, Utf8TakeRandom)
+    }
 }
 
+// Dispatch through the dtype-generic list builder (the same one `ChunkFilter<LargeListType>`
+// uses) rather than a per-primitive-type macro, so string, boolean and nested-list inner
+// types are all taken the same way as numeric ones instead of panicking.
 impl Take for LargeListChunked {
-    fn take(&self, indices: impl Iterator<Item = usize>, capacity: Option<usize>) -> Result<Self> {
-        let capacity = capacity.unwrap_or(indices.size_hint().0);
-
-        macro_rules! impl_list_take {
-            ($type:ty) => {{
-                let values_builder = PrimitiveBuilder::<$type>::new(capacity);
-                let mut builder =
-                    LargeListPrimitiveChunkedBuilder::new("take", values_builder, capacity);
-                let taker = self.take_rand();
-
-                for idx in indices {
-                    builder.append_opt_series(taker.get(idx).as_ref());
-                }
-                Ok(builder.finish())
-            }};
+    fn take(&self, indices: impl TakeIterator, capacity: Option<usize>) -> Result<Self> {
+        let capacity = capacity.unwrap_or(indices.len());
+        let taker = self.take_rand();
+        let mut builder = get_large_list_builder(self.get_inner_dtype(), capacity, "take");
+
+        for idx in indices {
+            builder.append_opt_series(&taker.get(idx));
         }
-        impl_list_take_apply_macro!(self, impl_list_take)
+        Ok(builder.finish())
     }
 
     unsafe fn take_unchecked(
         &self,
-        indices: impl Iterator<Item = usize>,
+        indices: impl TakeIterator,
         capacity: Option<usize>,
     ) -> Self {
-        let capacity = capacity.unwrap_or(indices.size_hint().0);
-
-        macro_rules! impl_list_take {
-            ($type:ty) => {{
-                let values_builder = PrimitiveBuilder::<$type>::new(capacity);
-                let mut builder =
-                    LargeListPrimitiveChunkedBuilder::new("take", values_builder, capacity);
-                let taker = self.take_rand();
-                for idx in indices {
-                    let v = taker.get_unchecked(idx);
-                    builder.append_opt_series(Some(&v));
-                }
-                builder.finish()
-            }};
-        }
+        let capacity = capacity.unwrap_or(indices.len());
+        let taker = self.take_rand();
+        let mut builder = get_large_list_builder(self.get_inner_dtype(), capacity, "take");
 
-        impl_list_take_apply_macro!(self, impl_list_take)
+        for idx in indices {
+            builder.append_opt_series(&Some(taker.get_unchecked(idx)));
+        }
+        builder.finish()
     }
 
     fn take_opt(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Result<Self> {
-        let capacity = capacity.unwrap_or(indices.size_hint().0);
-
-        macro_rules! impl_list_take {
-            ($type:ty) => {{
-                let values_builder = PrimitiveBuilder::<$type>::new(capacity);
-                let mut builder =
-                    LargeListPrimitiveChunkedBuilder::new("take", values_builder, capacity);
-                let taker = self.take_rand();
-
-                for opt_idx in indices {
-                    match opt_idx {
-                        Some(idx) => {
-                            let opt_s = taker.get(idx);
-                            builder.append_opt_series(opt_s.as_ref())
-                        }
-                        None => builder.append_null(),
-                    };
-                }
-                Ok(builder.finish())
-            }};
-        }
+        let capacity = capacity.unwrap_or(indices.len());
+        let taker = self.take_rand();
+        let mut builder = get_large_list_builder(self.get_inner_dtype(), capacity, "take");
 
-        impl_list_take_apply_macro!(self, impl_list_take)
+        for opt_idx in indices {
+            match opt_idx {
+                Some(idx) => builder.append_opt_series(&taker.get(idx)),
+                None => builder.append_null(),
+            };
+        }
+        Ok(builder.finish())
     }
 
     unsafe fn take_opt_unchecked(
         &self,
-        indices: impl Iterator<Item = Option<usize>>,
+        indices: impl TakeIteratorNulls,
         capacity: Option<usize>,
     ) -> Self {
-        let capacity = capacity.unwrap_or(indices.size_hint().0);
-
-        macro_rules! impl_list_take {
-            ($type:ty) => {{
-                let values_builder = PrimitiveBuilder::<$type>::new(capacity);
-                let mut builder =
-                    LargeListPrimitiveChunkedBuilder::new("take", values_builder, capacity);
-                let taker = self.take_rand();
-
-                for opt_idx in indices {
-                    match opt_idx {
-                        Some(idx) => {
-                            let s = taker.get_unchecked(idx);
-                            builder.append_opt_series(Some(&s))
-                        }
-                        None => builder.append_null(),
-                    };
-                }
-                builder.finish()
-            }};
-        }
+        let capacity = capacity.unwrap_or(indices.len());
+        let taker = self.take_rand();
+        let mut builder = get_large_list_builder(self.get_inner_dtype(), capacity, "take");
 
-        impl_list_take_apply_macro!(self, impl_list_take)
+        for opt_idx in indices {
+            match opt_idx {
+                Some(idx) => builder.append_opt_series(&Some(taker.get_unchecked(idx))),
+                None => builder.append_null(),
+            };
+        }
+        builder.finish()
     }
 }
 
 pub trait AsTakeIndex {
-    fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a>;
+    fn as_take_iter<'a>(&'a self) -> Box<dyn TakeIterator + 'a>;
 
-    fn as_opt_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = Option<usize>> + 'a> {
+    fn as_opt_take_iter<'a>(&'a self) -> Box<dyn TakeIteratorNulls + 'a> {
         unimplemented!()
     }
 
     fn take_index_len(&self) -> usize;
 }
 
+/// Wraps an iterator that drops `None` entries (a nullable index column skips its null
+/// positions rather than emitting them) so it can still report an exact remaining
+/// length, computed up front from the source's null count instead of re-scanning.
+struct ExactSizeFilterMap<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I> Iterator for ExactSizeFilterMap<I>
+where
+    I: Iterator<Item = Option<usize>>,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        for opt in &mut self.iter {
+            if let Some(v) = opt {
+                self.remaining -= 1;
+                return Some(v);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I> DoubleEndedIterator for ExactSizeFilterMap<I>
+where
+    I: DoubleEndedIterator<Item = Option<usize>>,
+{
+    fn next_back(&mut self) -> Option<usize> {
+        while let Some(opt) = self.iter.next_back() {
+            if let Some(v) = opt {
+                self.remaining -= 1;
+                return Some(v);
+            }
+        }
+        None
+    }
+}
+
+impl<I> ExactSizeIterator for ExactSizeFilterMap<I> where I: Iterator<Item = Option<usize>> {}
+
 impl AsTakeIndex for &UInt32Chunked {
-    fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
+    fn as_take_iter<'a>(&'a self) -> Box<dyn TakeIterator + 'a> {
         match self.cont_slice() {
-            Ok(slice) => Box::new(slice.into_iter().map(|&val| val as usize)),
-            Err(_) => Box::new(
-                self.into_iter()
-                    .filter_map(|opt_val| opt_val.map(|val| val as usize)),
-            ),
+            Ok(slice) => Box::new(slice.iter().map(|&val| val as usize)),
+            Err(_) => Box::new(ExactSizeFilterMap {
+                iter: self
+                    .into_iter()
+                    .map(|opt_val| opt_val.map(|val| val as usize)),
+                remaining: self.len() - self.null_count(),
+            }),
         }
     }
-    fn as_opt_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = Option<usize>> + 'a> {
+    fn as_opt_take_iter<'a>(&'a self) -> Box<dyn TakeIteratorNulls + 'a> {
         Box::new(
             self.into_iter()
                 .map(|opt_val| opt_val.map(|val| val as usize)),
@@ -416,7 +448,7 @@ impl AsTakeIndex for &UInt32Chunked {
 }
 
 impl AsTakeIndex for [usize] {
-    fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
+    fn as_take_iter<'a>(&'a self) -> Box<dyn TakeIterator + 'a> {
         Box::new(self.iter().copied())
     }
     fn take_index_len(&self) -> usize {
@@ -425,7 +457,7 @@ impl AsTakeIndex for [usize] {
 }
 
 impl AsTakeIndex for Vec<usize> {
-    fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
+    fn as_take_iter<'a>(&'a self) -> Box<dyn TakeIterator + 'a> {
         Box::new(self.iter().copied())
     }
     fn take_index_len(&self) -> usize {
@@ -434,7 +466,7 @@ impl AsTakeIndex for Vec<usize> {
 }
 
 impl AsTakeIndex for [u32] {
-    fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
+    fn as_take_iter<'a>(&'a self) -> Box<dyn TakeIterator + 'a> {
         Box::new(self.iter().map(|&v| v as usize))
     }
     fn take_index_len(&self) -> usize {
@@ -462,14 +494,75 @@ macro_rules! many_or_single {
         if chunks.len() == 1 {
             Box::new($StructSingle { arr: chunks[0] })
         } else {
-            Box::new($StructMany {
-                ca: $self,
-                chunks: chunks,
-            })
+            let offsets = ChunkOffsets::new(chunks.iter().map(|a| a.len()));
+            Box::new($StructMany { chunks, offsets })
         }
     }};
 }
 
+/// Precomputed cumulative chunk-length offsets for a multi-chunk array: `offsets[i]` is
+/// the global index of the first element of chunk `i`, with a trailing sentinel equal to
+/// the array's total length. Built once per gather instead of re-derived per element.
+struct ChunkOffsets(Vec<usize>);
+
+impl ChunkOffsets {
+    fn new(chunk_lens: impl Iterator<Item = usize>) -> Self {
+        let mut offsets = vec![0usize];
+        let mut acc = 0usize;
+        for len in chunk_lens {
+            acc += len;
+            offsets.push(acc);
+        }
+        ChunkOffsets(offsets)
+    }
+
+    /// Binary search for the chunk containing `index`, for arbitrary (non-sorted) access.
+    fn locate(&self, index: usize) -> (usize, usize) {
+        let chunk_idx = self.0.partition_point(|&o| o <= index) - 1;
+        (chunk_idx, index - self.0[chunk_idx])
+    }
+
+    fn cursor(&self) -> MonotonicCursor<'_> {
+        MonotonicCursor {
+            offsets: &self.0,
+            chunk_idx: 0,
+        }
+    }
+}
+
+/// Walks a [`ChunkOffsets`] forward only, for index sequences known to be non-decreasing
+/// ([`Take::take_sorted`]). Turns `ChunkOffsets::locate`'s binary search into an amortized
+/// O(1) advance, since a non-decreasing index can only ever move into the current or a
+/// later chunk. Feeding a decreasing index is a logic bug, not a memory-safety one: it
+/// just returns the wrong `(chunk_idx, arr_idx)` pair instead of panicking. An
+/// out-of-range index (decreasing or not) is handled safely: `advance` resolves it to an
+/// out-of-bounds chunk index so the caller's chunk lookup comes back `None`, the same
+/// behavior `ChunkOffsets::locate` gives the unsorted path.
+struct MonotonicCursor<'a> {
+    offsets: &'a [usize],
+    chunk_idx: usize,
+}
+
+impl<'a> MonotonicCursor<'a> {
+    fn advance(&mut self, index: usize) -> (usize, usize) {
+        let last_chunk_idx = self.offsets.len().saturating_sub(2);
+        while self.chunk_idx < last_chunk_idx && index >= self.offsets[self.chunk_idx + 1] {
+            self.chunk_idx += 1;
+        }
+        if index >= self.offsets[self.chunk_idx + 1] {
+            // `index` is past every chunk's range (out-of-range input). Return a
+            // chunk index one past the last real chunk, the same out-of-bounds
+            // `(chunk_idx, _)` `ChunkOffsets::locate` computes for an out-of-range
+            // index, so `chunks.get(chunk_idx)` resolves safely to `None` instead of
+            // `arr_idx` looking in-bounds for the wrong chunk.
+            return (self.offsets.len() - 1, 0);
+        }
+        // A decreasing index can sit before the current chunk; clamp instead of
+        // underflowing, so it yields a wrong row rather than a panic.
+        (self.chunk_idx, index.saturating_sub(self.offsets[self.chunk_idx]))
+    }
+}
+
 pub enum NumTakeRandomDispatch<'a, T>
 where
     T: PolarsNumericType,
@@ -521,10 +614,8 @@ where
                 if chunks.len() == 1 {
                     NumTakeRandomDispatch::Single(NumTakeRandomSingleChunk { arr: chunks[0] })
                 } else {
-                    NumTakeRandomDispatch::Many(NumTakeRandomChunked {
-                        ca: self,
-                        chunks: chunks,
-                    })
+                    let offsets = ChunkOffsets::new(chunks.iter().map(|a| a.len()));
+                    NumTakeRandomDispatch::Many(NumTakeRandomChunked { chunks, offsets })
                 }
             }
         }
@@ -561,9 +652,11 @@ impl<'a> IntoTakeRandom<'a> for &'a LargeListChunked {
                 name: self.name(),
             })
         } else {
+            let offsets = ChunkOffsets::new(chunks.iter().map(|a| a.len()));
             Box::new(ListTakeRandom {
                 ca: self,
                 chunks: chunks,
+                offsets,
             })
         }
     }
@@ -573,13 +666,13 @@ pub struct NumTakeRandomChunked<'a, T>
 where
     T: PolarsNumericType,
 {
-    ca: &'a ChunkedArray<T>,
     chunks: Vec<&'a PrimitiveArray<T>>,
+    offsets: ChunkOffsets,
 }
 
 macro_rules! take_random_get {
     ($self:ident, $index:ident) => {{
-        let (chunk_idx, arr_idx) = $self.ca.index_to_chunked_index($index);
+        let (chunk_idx, arr_idx) = $self.offsets.locate($index);
         let arr = $self.chunks.get(chunk_idx);
         match arr {
             Some(arr) => {
@@ -596,7 +689,7 @@ macro_rules! take_random_get {
 
 macro_rules! take_random_get_unchecked {
     ($self:ident, $index:ident) => {{
-        let (chunk_idx, arr_idx) = $self.ca.index_to_chunked_index($index);
+        let (chunk_idx, arr_idx) = $self.offsets.locate($index);
         $self.chunks.get_unchecked(chunk_idx).value(arr_idx)
     }};
 }
@@ -611,6 +704,23 @@ macro_rules! take_random_get_single {
     }};
 }
 
+/// Gather `indices` (assumed non-decreasing) by walking a single [`MonotonicCursor`]
+/// over `$self.offsets` instead of relocating the chunk boundary for every element.
+macro_rules! gather_with_offsets {
+    ($self:ident, $indices:ident) => {{
+        let mut cursor = $self.offsets.cursor();
+        $indices
+            .map(|index| {
+                let (chunk_idx, arr_idx) = cursor.advance(index);
+                match $self.chunks.get(chunk_idx) {
+                    Some(arr) if !arr.is_null(arr_idx) => Some(arr.value(arr_idx)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }};
+}
+
 impl<'a, T> TakeRandom for NumTakeRandomChunked<'a, T>
 where
     T: PolarsNumericType,
@@ -626,6 +736,18 @@ where
     }
 }
 
+impl<'a, T> NumTakeRandomChunked<'a, T>
+where
+    T: PolarsNumericType,
+{
+    fn gather_with_offsets(
+        &self,
+        indices: impl Iterator<Item = usize>,
+    ) -> Vec<Option<T::Native>> {
+        gather_with_offsets!(self, indices)
+    }
+}
+
 pub struct NumTakeRandomCont<'a, T> {
     slice: &'a [T],
 }
@@ -668,8 +790,8 @@ where
 }
 
 pub struct Utf8TakeRandom<'a> {
-    ca: &'a Utf8Chunked,
     chunks: Vec<&'a StringArray>,
+    offsets: ChunkOffsets,
 }
 
 impl<'a> TakeRandom for Utf8TakeRandom<'a> {
@@ -684,6 +806,12 @@ impl<'a> TakeRandom for Utf8TakeRandom<'a> {
     }
 }
 
+impl<'a> Utf8TakeRandom<'a> {
+    fn gather_with_offsets(&self, indices: impl Iterator<Item = usize>) -> Vec<Option<&'a str>> {
+        gather_with_offsets!(self, indices)
+    }
+}
+
 pub struct Utf8TakeRandomSingleChunk<'a> {
     arr: &'a StringArray,
 }
@@ -701,8 +829,8 @@ impl<'a> TakeRandom for Utf8TakeRandomSingleChunk<'a> {
 }
 
 pub struct BoolTakeRandom<'a> {
-    ca: &'a BooleanChunked,
     chunks: Vec<&'a BooleanArray>,
+    offsets: ChunkOffsets,
 }
 
 impl<'a> TakeRandom for BoolTakeRandom<'a> {
@@ -717,6 +845,12 @@ impl<'a> TakeRandom for BoolTakeRandom<'a> {
     }
 }
 
+impl<'a> BoolTakeRandom<'a> {
+    fn gather_with_offsets(&self, indices: impl Iterator<Item = usize>) -> Vec<Option<bool>> {
+        gather_with_offsets!(self, indices)
+    }
+}
+
 pub struct BoolTakeRandomSingleChunk<'a> {
     arr: &'a BooleanArray,
 }
@@ -768,3 +902,400 @@ impl<'a> TakeRandom for ListTakeRandomSingleChunk<'a> {
         (self.name, self.arr.value(index)).into()
     }
 }
+
+/// Property-based differential testing for `Take`/`TakeRandom`, fuzzing both the
+/// single-chunk and multi-chunk dispatch paths against a trivial reference gather.
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use arrow::array::{ArrayRef, BooleanBuilder, StringBuilder};
+    use quickcheck::{Arbitrary, Gen, TestResult};
+    use quickcheck_macros::quickcheck;
+    use std::sync::Arc;
+
+    /// Draw a sorted, deduplicated set of cut points in `1..len`, so building the chunks
+    /// around them always yields at least one (possibly empty) chunk and exercises both
+    /// the single-chunk and multi-chunk `take_rand` paths depending on how many land.
+    fn arbitrary_cuts(len: usize, g: &mut Gen) -> Vec<usize> {
+        if len < 2 {
+            return vec![];
+        }
+        let n_cuts = usize::arbitrary(g) % 4;
+        let mut cuts: Vec<usize> = (0..n_cuts).map(|_| 1 + usize::arbitrary(g) % (len - 1)).collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+        cuts
+    }
+
+    fn segments<T: Clone>(values: &[T], cuts: &[usize]) -> Vec<Vec<T>> {
+        let mut out = Vec::with_capacity(cuts.len() + 1);
+        let mut start = 0;
+        for &cut in cuts {
+            out.push(values[start..cut].to_vec());
+            start = cut;
+        }
+        out.push(values[start..].to_vec());
+        out
+    }
+
+    /// A `ChunkedArray<T>` built from an arbitrary value/null mix, arbitrarily split
+    /// across chunks.
+    #[derive(Clone, Debug)]
+    struct ArbNumChunked(Int32Chunked);
+
+    impl Arbitrary for ArbNumChunked {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 30;
+            let values: Vec<Option<i32>> = (0..len)
+                .map(|_| bool::arbitrary(g).then(|| i32::arbitrary(g)))
+                .collect();
+            let cuts = arbitrary_cuts(len, g);
+            let chunks = segments(&values, &cuts)
+                .into_iter()
+                .map(|seg| {
+                    let mut builder = PrimitiveBuilder::<Int32Type>::new(seg.len());
+                    for v in seg {
+                        match v {
+                            Some(v) => builder.append_value(v).unwrap(),
+                            None => builder.append_null().unwrap(),
+                        }
+                    }
+                    Arc::new(builder.finish()) as ArrayRef
+                })
+                .collect();
+            ArbNumChunked(ChunkedArray::new_from_chunks("a", chunks))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let values: Vec<Option<i32>> = self.0.into_iter().collect();
+            Box::new(
+                values
+                    .shrink()
+                    .map(|v| ArbNumChunked(Int32Chunked::new_from_opt_slice("a", &v))),
+            )
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ArbBoolChunked(BooleanChunked);
+
+    impl Arbitrary for ArbBoolChunked {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 30;
+            let values: Vec<Option<bool>> = (0..len)
+                .map(|_| bool::arbitrary(g).then(|| bool::arbitrary(g)))
+                .collect();
+            let cuts = arbitrary_cuts(len, g);
+            let chunks = segments(&values, &cuts)
+                .into_iter()
+                .map(|seg| {
+                    let mut builder = BooleanBuilder::new(seg.len());
+                    for v in seg {
+                        match v {
+                            Some(v) => builder.append_value(v).unwrap(),
+                            None => builder.append_null().unwrap(),
+                        }
+                    }
+                    Arc::new(builder.finish()) as ArrayRef
+                })
+                .collect();
+            ArbBoolChunked(ChunkedArray::new_from_chunks("a", chunks))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let values: Vec<Option<bool>> = self.0.into_iter().collect();
+            Box::new(
+                values
+                    .shrink()
+                    .map(|v| ArbBoolChunked(BooleanChunked::new_from_opt_slice("a", &v))),
+            )
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ArbUtf8Chunked(Utf8Chunked);
+
+    impl Arbitrary for ArbUtf8Chunked {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 30;
+            let values: Vec<Option<String>> = (0..len)
+                .map(|_| bool::arbitrary(g).then(|| String::arbitrary(g)))
+                .collect();
+            let cuts = arbitrary_cuts(len, g);
+            let chunks = segments(&values, &cuts)
+                .into_iter()
+                .map(|seg| {
+                    let mut builder = StringBuilder::new(seg.len());
+                    for v in seg {
+                        match v {
+                            Some(v) => builder.append_value(&v).unwrap(),
+                            None => builder.append_null().unwrap(),
+                        }
+                    }
+                    Arc::new(builder.finish()) as ArrayRef
+                })
+                .collect();
+            ArbUtf8Chunked(ChunkedArray::new_from_chunks("a", chunks))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let values: Vec<Option<String>> = self
+                .0
+                .into_iter()
+                .map(|opt_s| opt_s.map(|s| s.to_string()))
+                .collect();
+            Box::new(
+                values
+                    .shrink()
+                    .map(|v| ArbUtf8Chunked(Utf8Chunked::new_from_opt_slice("a", &v))),
+            )
+        }
+    }
+
+    /// `LargeListChunked` generation is kept to a single chunk: there's no public API in
+    /// this crate to stitch pre-built list chunks back together the way the primitive
+    /// builders above do, so only the single-chunk `take_rand` path is exercised here.
+    #[derive(Clone, Debug)]
+    struct ArbListChunked(LargeListChunked);
+
+    impl Arbitrary for ArbListChunked {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 10;
+            let values_builder = PrimitiveBuilder::<Int32Type>::new(len * 3);
+            let mut builder = LargeListPrimitiveChunkedBuilder::new("a", values_builder, len);
+            for _ in 0..len {
+                if bool::arbitrary(g) {
+                    let inner_len = usize::arbitrary(g) % 4;
+                    let inner: Vec<i32> = (0..inner_len).map(|_| i32::arbitrary(g)).collect();
+                    builder.append_opt_series(Some(&Series::new("", inner.as_slice())));
+                } else {
+                    builder.append_opt_series(None);
+                }
+            }
+            ArbListChunked(builder.finish())
+        }
+    }
+
+    /// A mix of in-bounds, duplicate, and out-of-range indices relative to whatever
+    /// array it's paired with: the index range is independent of the array length, so
+    /// shrinking/growing either side naturally produces all three cases.
+    #[derive(Clone, Debug)]
+    struct ArbIndices(Vec<usize>);
+
+    impl Arbitrary for ArbIndices {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 20;
+            ArbIndices((0..len).map(|_| usize::arbitrary(g) % 40).collect())
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().map(ArbIndices))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ArbOptIndices(Vec<Option<usize>>);
+
+    impl Arbitrary for ArbOptIndices {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 20;
+            ArbOptIndices(
+                (0..len)
+                    .map(|_| bool::arbitrary(g).then(|| usize::arbitrary(g) % 40))
+                    .collect(),
+            )
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().map(ArbOptIndices))
+        }
+    }
+
+    #[quickcheck]
+    fn num_take_matches_reference_gather(ca: ArbNumChunked, idx: ArbIndices) -> TestResult {
+        let ca = ca.0;
+        if idx.0.iter().any(|&i| i >= ca.len()) {
+            return TestResult::discard();
+        }
+        let taken = ca.take(idx.0.iter().copied(), None).unwrap();
+        let reference: Vec<Option<i32>> = idx.0.iter().map(|&i| ca.get(i)).collect();
+        TestResult::from_bool(Vec::from(&taken) == reference)
+    }
+
+    #[quickcheck]
+    fn num_take_out_of_range_is_err_or_null(ca: ArbNumChunked, idx: ArbIndices) -> bool {
+        let ca = ca.0;
+        if idx.0.iter().all(|&i| i < ca.len()) {
+            return true;
+        }
+        // `take` over raw `usize` indices has no way to signal "no value" per element,
+        // so an out-of-range index must not be allowed to panic.
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ca.take(idx.0.iter().copied(), None)
+        }))
+        .is_ok()
+    }
+
+    #[quickcheck]
+    fn num_take_sorted_out_of_range_is_err_or_null(ca: ArbNumChunked, idx: ArbIndices) -> bool {
+        let ca = ca.0;
+        let mut sorted_idx = idx.0;
+        sorted_idx.sort_unstable();
+        if sorted_idx.iter().all(|&i| i < ca.len()) {
+            return true;
+        }
+        // `take_sorted` walks `MonotonicCursor` instead of `ChunkOffsets::locate`'s
+        // binary search -- an out-of-range index must resolve the same safe way the
+        // unsorted `take` path does (`None`, not a panic from reading past a chunk).
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ca.take_sorted(sorted_idx.iter().copied(), None)
+        }))
+        .is_ok()
+    }
+
+    #[quickcheck]
+    fn num_take_unchecked_matches_take_when_in_bounds(ca: ArbNumChunked, idx: ArbIndices) -> TestResult {
+        let ca = ca.0;
+        if idx.0.iter().any(|&i| i >= ca.len()) {
+            return TestResult::discard();
+        }
+        let checked = ca.take(idx.0.iter().copied(), None).unwrap();
+        let unchecked = unsafe { ca.take_unchecked(idx.0.iter().copied(), None) };
+        TestResult::from_bool(Vec::from(&checked) == Vec::from(&unchecked))
+    }
+
+    #[quickcheck]
+    fn num_take_opt_matches_reference_gather(ca: ArbNumChunked, idx: ArbOptIndices) -> TestResult {
+        let ca = ca.0;
+        if idx.0.iter().any(|i| matches!(i, Some(i) if *i >= ca.len())) {
+            return TestResult::discard();
+        }
+        let taken = ca.take_opt(idx.0.iter().copied(), None).unwrap();
+        let reference: Vec<Option<i32>> = idx.0.iter().map(|i| i.and_then(|i| ca.get(i))).collect();
+        TestResult::from_bool(Vec::from(&taken) == reference)
+    }
+
+    #[quickcheck]
+    fn bool_take_matches_reference_gather(ca: ArbBoolChunked, idx: ArbIndices) -> TestResult {
+        let ca = ca.0;
+        if idx.0.iter().any(|&i| i >= ca.len()) {
+            return TestResult::discard();
+        }
+        let taken = ca.take(idx.0.iter().copied(), None).unwrap();
+        let reference: Vec<Option<bool>> = idx.0.iter().map(|&i| ca.get(i)).collect();
+        TestResult::from_bool(Vec::from(&taken) == reference)
+    }
+
+    #[quickcheck]
+    fn utf8_take_matches_reference_gather(ca: ArbUtf8Chunked, idx: ArbIndices) -> TestResult {
+        let ca = ca.0;
+        if idx.0.iter().any(|&i| i >= ca.len()) {
+            return TestResult::discard();
+        }
+        let taken = ca.take(idx.0.iter().copied(), None).unwrap();
+        let reference: Vec<Option<&str>> = idx.0.iter().map(|&i| ca.get(i)).collect();
+        TestResult::from_bool(Vec::from(&taken) == reference)
+    }
+
+    #[quickcheck]
+    fn list_take_matches_reference_gather(ca: ArbListChunked, idx: ArbIndices) -> TestResult {
+        let ca = ca.0;
+        if idx.0.iter().any(|&i| i >= ca.len()) {
+            return TestResult::discard();
+        }
+        let taken = ca.take(idx.0.iter().copied(), None).unwrap();
+        let source_taker = ca.take_rand();
+        let taken_taker = taken.take_rand();
+        for (got, &i) in (0..idx.0.len()).zip(idx.0.iter()) {
+            match (taken_taker.get(got), source_taker.get(i)) {
+                (None, None) => {}
+                (Some(a), Some(b)) => {
+                    if Vec::from(a.i32().unwrap()) != Vec::from(b.i32().unwrap()) {
+                        return TestResult::failed();
+                    }
+                }
+                _ => return TestResult::failed(),
+            }
+        }
+        TestResult::passed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn take_rev_and_take_last() {
+        let ca = Int32Chunked::new_from_slice("a", &[0, 1, 2, 3, 4]);
+        let idx = [1usize, 3, 4];
+
+        let reversed = ca.take_rev(idx.iter().copied(), None).unwrap();
+        assert_eq!(Vec::from(&reversed), &[Some(4), Some(3), Some(1)]);
+
+        let last_two = ca.take_last(idx.iter().copied(), 2).unwrap();
+        assert_eq!(Vec::from(&last_two), &[Some(3), Some(4)]);
+    }
+
+    #[test]
+    fn as_take_iter_is_exact_size() {
+        let idx = UInt32Chunked::new_from_opt_slice("idx", &[Some(0), None, Some(2)]);
+        // two non-null entries, so the exact length must skip the null rather than
+        // merely lower-bounding at 0 like the old `filter_map`-based `size_hint` did
+        assert_eq!((&idx).as_take_iter().len(), 2);
+    }
+
+    #[test]
+    fn take_sorted_matches_take_on_multi_chunk() {
+        use arrow::array::PrimitiveBuilder;
+        use arrow::datatypes::Int32Type;
+        use std::sync::Arc;
+
+        let mut a = PrimitiveBuilder::<Int32Type>::new(3);
+        let mut b = PrimitiveBuilder::<Int32Type>::new(3);
+        for v in &[0, 1, 2] {
+            a.append_value(*v).unwrap();
+        }
+        for v in &[3, 4, 5] {
+            b.append_value(*v).unwrap();
+        }
+        let chunks: Vec<arrow::array::ArrayRef> =
+            vec![Arc::new(a.finish()), Arc::new(b.finish())];
+        let ca = ChunkedArray::<Int32Type>::new_from_chunks("a", chunks);
+
+        let idx = [1usize, 2, 4, 5];
+        let sorted = ca.take_sorted(idx.iter().copied(), None).unwrap();
+        let plain = ca.take(idx.iter().copied(), None).unwrap();
+        assert_eq!(Vec::from(&sorted), Vec::from(&plain));
+        assert_eq!(Vec::from(&sorted), &[Some(1), Some(2), Some(4), Some(5)]);
+    }
+
+    #[test]
+    fn take_list_of_utf8() {
+        let rows = [
+            Some(Series::new("", &["a", "b"])),
+            None,
+            Some(Series::new("", &["c"])),
+        ];
+        let mut builder = get_large_list_builder(&ArrowDataType::Utf8, rows.len(), "a");
+        for row in &rows {
+            builder.append_opt_series(row);
+        }
+        let ca = builder.finish();
+
+        // reorders rows and drops the null in the middle
+        let out = ca.take(vec![2usize, 0].into_iter(), None).unwrap();
+        let formatted = format!("{:?}", out.into_series());
+        assert!(formatted.contains('c'));
+        assert!(formatted.contains('a'));
+        assert!(formatted.contains('b'));
+
+        // both an index pointing at the null row and a `None` index turn into null rows
+        let opt_out = ca
+            .take_opt(vec![Some(1usize), None].into_iter(), None)
+            .unwrap();
+        let opt_formatted = format!("{:?}", opt_out.into_series());
+        assert_eq!(opt_formatted.matches("null").count(), 2);
+    }
+}
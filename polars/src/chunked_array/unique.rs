@@ -142,6 +142,101 @@ where
     }
 }
 
+/// Union-find (disjoint-set-union) with path splitting and union by size. A negative
+/// entry `-s` marks a root holding a tree of `s` elements; a non-negative entry is a
+/// parent pointer.
+struct DisjointSet {
+    parent: Vec<isize>,
+}
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: vec![-1; n],
+        }
+    }
+
+    fn root(&mut self, mut u: usize) -> usize {
+        while self.parent[u] >= 0 {
+            let p = self.parent[u] as usize;
+            if self.parent[p] >= 0 {
+                self.parent[u] = self.parent[p];
+            }
+            u = p;
+        }
+        u
+    }
+
+    fn unite(&mut self, u: usize, v: usize) {
+        let ru = self.root(u);
+        let rv = self.root(v);
+        if ru == rv {
+            return;
+        }
+        let (big, small) = if -self.parent[ru] >= -self.parent[rv] {
+            (ru, rv)
+        } else {
+            (rv, ru)
+        };
+        self.parent[big] += self.parent[small];
+        self.parent[small] = big as isize;
+    }
+}
+
+/// Connected-component labeling over an edge list: given `n` nodes and two index
+/// `ChunkedArray`s `src`/`dst` of the same length, union every edge and return a dense
+/// `0..k` component id per node. Pairs the same way as the `value_counts` map, e.g.
+/// `connected_components(n, &src, &dst)?.value_counts()` summarizes component sizes.
+pub fn connected_components<T>(
+    n: usize,
+    src: &ChunkedArray<T>,
+    dst: &ChunkedArray<T>,
+) -> Result<ChunkedArray<UInt32Type>>
+where
+    T: PolarsIntegerType,
+    T::Native: ToPrimitive,
+{
+    if src.len() != dst.len() {
+        return Err(PolarsError::LengthMismatch(src.len(), dst.len()));
+    }
+
+    let mut dsu = DisjointSet::new(n);
+    for (a, b) in src.into_iter().zip(dst.into_iter()) {
+        if let (Some(a), Some(b)) = (a, b) {
+            let a = a
+                .to_usize()
+                .ok_or_else(|| PolarsError::InvalidOperation("node id does not fit in usize".into()))?;
+            let b = b
+                .to_usize()
+                .ok_or_else(|| PolarsError::InvalidOperation("node id does not fit in usize".into()))?;
+            if a >= n || b >= n {
+                return Err(PolarsError::OutOfBounds {
+                    index: a.max(b),
+                    len: n,
+                });
+            }
+            dsu.unite(a, b);
+        }
+    }
+
+    // Relabel roots to dense 0..k ids.
+    let mut assigned: Vec<Option<u32>> = vec![None; n];
+    let mut next_id = 0u32;
+    let mut labels = Vec::with_capacity(n);
+    for u in 0..n {
+        let r = dsu.root(u);
+        let id = *assigned[r].get_or_insert_with(|| {
+            let id = next_id;
+            next_id += 1;
+            id
+        });
+        labels.push(Some(id));
+    }
+
+    let builder = PrimitiveChunkedBuilder::new(src.name(), n);
+    Ok(builder.new_from_iter(labels))
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -160,4 +255,28 @@ mod test {
             vec![Some(true), Some(false)]
         );
     }
+
+    #[test]
+    fn connected_components_labels_groups() {
+        let src = ChunkedArray::<Int32Type>::new_from_slice("src", &[0, 1, 3]);
+        let dst = ChunkedArray::<Int32Type>::new_from_slice("dst", &[1, 2, 4]);
+        let labels = connected_components(5, &src, &dst).unwrap();
+        let labels = labels.into_iter().map(|v| v.unwrap()).collect_vec();
+
+        // nodes 0,1,2 end up in one component, 3,4 in another.
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[1], labels[2]);
+        assert_eq!(labels[3], labels[4]);
+        assert_ne!(labels[0], labels[3]);
+    }
+
+    #[test]
+    fn connected_components_rejects_length_mismatch() {
+        let src = ChunkedArray::<Int32Type>::new_from_slice("src", &[0, 1]);
+        let dst = ChunkedArray::<Int32Type>::new_from_slice("dst", &[1]);
+        assert!(matches!(
+            connected_components(2, &src, &dst),
+            Err(PolarsError::LengthMismatch(2, 1))
+        ));
+    }
 }
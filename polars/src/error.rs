@@ -1,29 +1,71 @@
+use std::borrow::Cow;
 use thiserror::Error as ThisError;
 
+/// Structured error type for Polars operations.
+///
+/// Each variant carries the context a caller needs to match on the failure kind
+/// programmatically (e.g. `matches!(err.root_cause(), PolarsError::OutOfBounds { .. })`)
+/// instead of parsing the `Display` string. Use [`PolarsError::context`] to attach an
+/// outer explanation to a lower-level error while chaining it as the `source`, and
+/// [`PolarsError::root_cause`] to walk back down to the original failure kind.
 #[derive(Debug, ThisError)]
 pub enum PolarsError {
     #[error(transparent)]
     ArrowError(#[from] arrow::error::ArrowError),
-    #[error("Invalid operation")]
-    InvalidOperation,
-    #[error("Chunk don't match")]
+    #[error("invalid operation: {0}")]
+    InvalidOperation(Cow<'static, str>),
+    #[error("shape mismatch: expected {expected:?}, got {got:?}")]
+    ShapeMisMatch {
+        expected: (usize, usize),
+        got: (usize, usize),
+    },
+    #[error("lengths don't match: {0} vs {1}")]
+    LengthMismatch(usize, usize),
+    #[error("chunks don't match")]
     ChunkMisMatch,
-    #[error("Data types don't match")]
+    #[error("data types don't match")]
     DataTypeMisMatch,
-    #[error("Not found")]
-    NotFound,
-    #[error("Lengths don't match")]
-    LengthMismatch,
-    #[error("{0}")]
-    Other(String),
-    #[error("No selection was made")]
-    NoSelection,
-    #[error("Out of bounds")]
-    OutOfBounds,
-    #[error("Not contiguous or null values")]
+    #[error("not found: {0}")]
+    NotFound(Cow<'static, str>),
+    #[error("column contains null values: {0}")]
+    HasNullValues(Cow<'static, str>),
+    #[error("out of bounds: index {index} for length {len}")]
+    OutOfBounds { index: usize, len: usize },
+    #[error("not contiguous or null values")]
     NoSlice,
-    #[error("Such empty...")]
-    NoData,
+    #[error("no data: {0}")]
+    NoData(Cow<'static, str>),
+    #[error("no selection was made")]
+    NoSelection,
+    #[error("{0}")]
+    Other(Cow<'static, str>),
+    #[error("{msg}")]
+    Context {
+        msg: String,
+        #[source]
+        source: Box<PolarsError>,
+    },
+}
+
+impl PolarsError {
+    /// Wraps `self` behind an outer message, keeping `self` as the `source` of the
+    /// returned error so the original failure kind is still reachable via
+    /// `root_cause`/`std::error::Error::source` rather than lost to a flattened string.
+    pub fn context(self, msg: impl Into<String>) -> Self {
+        PolarsError::Context {
+            msg: msg.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// The innermost non-`Context` error in the chain, i.e. `self` with any `context`
+    /// wrapping stripped away. This is the variant to `match`/`matches!` on.
+    pub fn root_cause(&self) -> &PolarsError {
+        match self {
+            PolarsError::Context { source, .. } => source.root_cause(),
+            other => other,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, PolarsError>;
@@ -11,11 +11,62 @@ use crate::chunked_array::temporal::{
 use num::{Num, NumCast};
 #[cfg(feature = "pretty")]
 use prettytable::Table;
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::{
     fmt,
     fmt::{Debug, Display, Formatter},
 };
 
+/// Knobs controlling how `Series`/`DataFrame` are rendered by their `Debug`/`Display`
+/// impls. Mirrors the null-string and safe-mode knobs arrow's display layer exposes, so
+/// callers can tune output without having to change call sites.
+#[derive(Clone, Debug)]
+pub struct FormatOptions {
+    /// Maximum number of rows rendered per `Series`/`DataFrame`.
+    pub max_rows: usize,
+    /// String written for a missing value.
+    pub null: Cow<'static, str>,
+    /// Decimal places floats are rounded to before printing. `None` keeps full precision.
+    pub float_precision: Option<usize>,
+    /// Values greater than this threshold are printed in scientific notation. Floats
+    /// below `0.001` (including zero and every negative float) are printed that way too.
+    pub scientific_threshold: f64,
+    /// When `true`, a formatting error is written into the output as text instead of
+    /// bubbling up as `fmt::Error`.
+    pub safe: bool,
+    /// IANA timezone (e.g. `"Europe/Amsterdam"`) timestamps are localized to before
+    /// rendering. `None` prints the stored UTC value, as before.
+    pub tz: Option<String>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: 10,
+            null: Cow::Borrowed("null"),
+            float_precision: Some(3),
+            scientific_threshold: 9999.,
+            safe: true,
+            tz: None,
+        }
+    }
+}
+
+thread_local! {
+    static FORMAT_OPTIONS: RefCell<FormatOptions> = RefCell::new(FormatOptions::default());
+}
+
+/// Replace the thread's active `FormatOptions`.
+pub fn set_format_options(options: FormatOptions) {
+    FORMAT_OPTIONS.with(|cell| *cell.borrow_mut() = options);
+}
+
+/// Read a copy of the thread's active `FormatOptions`.
+pub fn format_options() -> FormatOptions {
+    FORMAT_OPTIONS.with(|cell| cell.borrow().clone())
+}
+
 /// Some unit functions that just pass the integer values if we don't want all chrono functionality
 #[cfg(not(feature = "temporal"))]
 mod temporal {
@@ -66,10 +117,35 @@ mod temporal {
 #[cfg(not(feature = "temporal"))]
 use temporal::*;
 
+/// Write a timestamp, localizing it to `opts.tz` first if one is set.
+///
+/// Falls back to the naive (UTC) value when `opts.tz` is `None`, unparseable (with a
+/// note naming the timezone; with `opts.safe` off this returns `fmt::Error` instead), or
+/// when no timezone database is available (the `not(feature = "temporal")` build, where
+/// timestamps are plain integers and have no notion of a timezone).
+#[cfg(feature = "temporal")]
+fn fmt_timestamp(f: &mut Formatter<'_>, naive: chrono::NaiveDateTime, opts: &FormatOptions) -> fmt::Result {
+    use chrono::TimeZone;
+
+    match &opts.tz {
+        Some(tz_str) => match tz_str.parse::<chrono_tz::Tz>() {
+            Ok(tz) => write!(f, "{}", tz.from_utc_datetime(&naive)),
+            Err(_) if opts.safe => write!(f, "{} (unknown timezone {:?})", naive, tz_str),
+            Err(_) => Err(fmt::Error),
+        },
+        None => write!(f, "{}", naive),
+    }
+}
+
+#[cfg(not(feature = "temporal"))]
+fn fmt_timestamp<T: fmt::Display>(f: &mut Formatter<'_>, v: T, _opts: &FormatOptions) -> fmt::Result {
+    write!(f, "{}", v)
+}
+
 impl Debug for Series {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        const LIMIT: usize = 10;
-        let limit = std::cmp::min(self.len(), LIMIT);
+        let opts = format_options();
+        let limit = std::cmp::min(self.len(), opts.max_rows);
 
         macro_rules! format_series {
             ($a:ident, $name:expr) => {{
@@ -77,7 +153,31 @@ impl Debug for Series {
 
                 for i in 0..limit {
                     let v = $a.get(i);
-                    write!(f, "\t{}\n", v)?;
+                    let mut buf = String::new();
+                    match fmt::Write::write_fmt(&mut buf, format_args!("{}", v)) {
+                        Ok(_) => write!(f, "\t{}\n", buf)?,
+                        Err(e) if opts.safe => write!(f, "\t<format error: {}>\n", e)?,
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                write![f, "]"]
+            }};
+        }
+
+        macro_rules! format_series_interval {
+            ($a:ident, $name:expr, $fmt_fn:path) => {{
+                write![f, "Series: {}\n[\n", $name]?;
+
+                for i in 0..limit {
+                    match $a.get(i) {
+                        None => write!(f, "\t{}\n", opts.null)?,
+                        Some(v) => {
+                            write!(f, "\t")?;
+                            $fmt_fn(f, v)?;
+                            write!(f, "\n")?;
+                        }
+                    }
                 }
 
                 write![f, "]"]
@@ -106,29 +206,73 @@ impl Debug for Series {
             Series::DurationMicrosecond(a) => format_series!(a, "duration(μs)"),
             Series::DurationMillisecond(a) => format_series!(a, "duration(ms)"),
             Series::DurationSecond(a) => format_series!(a, "duration(s)"),
-            Series::IntervalDayTime(a) => format_series!(a, "interval(daytime)"),
-            Series::IntervalYearMonth(a) => format_series!(a, "interval(year-month)"),
+            Series::IntervalDayTime(a) => {
+                format_series_interval!(a, "interval(daytime)", fmt_interval_dt)
+            }
+            Series::IntervalYearMonth(a) => {
+                format_series_interval!(a, "interval(year-month)", fmt_interval_ym)
+            }
             Series::TimestampNanosecond(a) => format_series!(a, "timestamp(ns)"),
             Series::TimestampMicrosecond(a) => format_series!(a, "timestamp(μs)"),
             Series::TimestampMillisecond(a) => format_series!(a, "timestamp(ms)"),
             Series::TimestampSecond(a) => format_series!(a, "timestamp(s)"),
             Series::Utf8(a) => {
                 write![f, "Series: str \n[\n"]?;
-                a.into_iter().take(LIMIT).for_each(|opt_s| match opt_s {
+                a.into_iter().take(limit).for_each(|opt_s| match opt_s {
                     None => {
-                        write!(f, "\tnull\n").ok();
+                        write!(f, "\t{}\n", opts.null).ok();
                     }
                     Some(s) => {
-                        write!(f, "\t\"{}\"\n", &s[..std::cmp::min(LIMIT, s.len())]).ok();
+                        write!(f, "\t\"{}\"\n", &s[..std::cmp::min(opts.max_rows, s.len())]).ok();
                     }
                 });
                 write![f, "]"]
             }
-            Series::List(a) => todo!(),
+            Series::List(a) => {
+                write![f, "Series: list \n[\n"]?;
+                for i in 0..limit {
+                    match a.get(i) {
+                        None => write!(f, "\t{}\n", opts.null)?,
+                        Some(sub) => write!(f, "\t{}\n", fmt_list_value(&sub))?,
+                    }
+                }
+                write![f, "]"]
+            }
+            // NOTE: `Series::Struct` itself, its `ChunkLen`/`enum_dispatch` impls, and the
+            // JSONL/Parquet builder support that would actually populate one all live in
+            // the core datatype definitions, which this tree doesn't carry. This arm is
+            // the formatting half of that cross-cutting change, written against the shape
+            // described for it (a struct Series backed by named child `Series`) so it's
+            // ready to light up once the variant lands upstream.
+            Series::Struct(fields) => {
+                write![f, "Series: struct \n[\n"]?;
+                for i in 0..limit {
+                    let row: Vec<String> = fields
+                        .iter()
+                        .map(|field| format!("{}: {}", field.name(), field.get(i)))
+                        .collect();
+                    write!(f, "\t{{{}}}\n", row.join(", "))?;
+                }
+                write![f, "]"]
+            }
         }
     }
 }
 
+/// Render a list-typed row inline as `[v0, v1, ...]`, truncated to a small element cap
+/// (with an ellipsis) so nested list columns don't blow up the printed frame. Each
+/// element is rendered through the same per-`AnyType` `Display` path as top-level
+/// values, so temporal/interval inner types render correctly too.
+fn fmt_list_value(series: &Series) -> String {
+    const ELEMENT_CAP: usize = 3;
+    let n = std::cmp::min(series.len(), ELEMENT_CAP);
+    let mut values: Vec<String> = (0..n).map(|i| format!("{}", series.get(i))).collect();
+    if series.len() > ELEMENT_CAP {
+        values.push("...".into());
+    }
+    format!("[{}]", values.join(", "))
+}
+
 impl Display for Series {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         Debug::fmt(self, f)
@@ -152,7 +296,8 @@ impl Display for DataFrame {
             .map(|f| format!("{}\n---\n{}", f.name(), f.data_type().to_str()))
             .collect();
         table.set_titles(names);
-        for i in 0..10 {
+        let opts = format_options();
+        for i in 0..opts.max_rows {
             let opt = self.get(i);
             if let Some(row) = opt {
                 let mut row_str = Vec::with_capacity(row.len());
@@ -180,18 +325,49 @@ impl Display for DataFrame {
 }
 
 fn fmt_integer<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt::Result {
+    let opts = format_options();
     let v: i64 = NumCast::from(v).unwrap();
-    if v > 9999 {
+    if v as f64 > opts.scientific_threshold {
         write!(f, "{:>width$e}", v, width = width)
     } else {
         write!(f, "{:>width$}", v, width = width)
     }
 }
 
+/// Unpack a year-month interval (total months) into `"{years}y {months}mo"`.
+fn fmt_interval_ym(f: &mut Formatter<'_>, v: i32) -> fmt::Result {
+    let years = v / 12;
+    let months = v % 12;
+    write!(f, "{}y {}mo", years, months)
+}
+
+/// Unpack a day-time interval into `"{days}d {hh:mm:ss.mmm}"`. The high 32 bits hold the
+/// day count, the low 32 bits hold the millisecond-of-day.
+fn fmt_interval_dt(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
+    let days = (v >> 32) as i32;
+    let millis_of_day = v as i32;
+    let hours = millis_of_day / 3_600_000;
+    let minutes = (millis_of_day / 60_000) % 60;
+    let seconds = (millis_of_day / 1000) % 60;
+    let millis = millis_of_day % 1000;
+    write!(
+        f,
+        "{}d {:02}:{:02}:{:02}.{:03}",
+        days, hours, minutes, seconds, millis
+    )
+}
+
 fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt::Result {
+    let opts = format_options();
     let v: f64 = NumCast::from(v).unwrap();
-    let v = (v * 1000.).round() / 1000.;
-    if v > 9999. || v < 0.001 {
+    let v = match opts.float_precision {
+        Some(precision) => {
+            let factor = 10f64.powi(precision as i32);
+            (v * factor).round() / factor
+        }
+        None => v,
+    };
+    if v > opts.scientific_threshold || v < 0.001 {
         write!(f, "{:>width$e}", v, width = width)
     } else {
         write!(f, "{:>width$}", v, width = width)
@@ -210,7 +386,7 @@ impl Display for AnyType<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let width = 0;
         match self {
-            AnyType::Null => write!(f, "{}", "null"),
+            AnyType::Null => write!(f, "{}", format_options().null),
             AnyType::UInt8(v) => write!(f, "{}", v),
             AnyType::UInt16(v) => write!(f, "{}", v),
             AnyType::UInt32(v) => write!(f, "{}", v),
@@ -240,19 +416,30 @@ impl Display for AnyType<'_> {
             AnyType::Duration(v, TimeUnit::Millisecond) => write!(f, "{}", v),
             AnyType::Duration(v, TimeUnit::Second) => write!(f, "{}", v),
             AnyType::TimeStamp(v, TimeUnit::Nanosecond) => {
-                write!(f, "{}", timestamp_nanoseconds_as_datetime(*v))
+                fmt_timestamp(f, timestamp_nanoseconds_as_datetime(*v), &format_options())
             }
             AnyType::TimeStamp(v, TimeUnit::Microsecond) => {
-                write!(f, "{}", timestamp_microseconds_as_datetime(*v))
+                fmt_timestamp(f, timestamp_microseconds_as_datetime(*v), &format_options())
             }
             AnyType::TimeStamp(v, TimeUnit::Millisecond) => {
-                write!(f, "{}", timestamp_milliseconds_as_datetime(*v))
+                fmt_timestamp(f, timestamp_milliseconds_as_datetime(*v), &format_options())
             }
             AnyType::TimeStamp(v, TimeUnit::Second) => {
-                write!(f, "{}", timestamp_seconds_as_datetime(*v))
+                fmt_timestamp(f, timestamp_seconds_as_datetime(*v), &format_options())
+            }
+            AnyType::IntervalDayTime(v) => fmt_interval_dt(f, *v),
+            AnyType::IntervalYearMonth(v) => fmt_interval_ym(f, *v),
+            // See the note on `Series::Struct` in `Debug for Series`: the variant and its
+            // `(name, value)` field shape aren't carried by this tree, but the rendering
+            // it needs is the same `{field0: v0, field1: v1, ...}` recursion into each
+            // child's own `Display`.
+            AnyType::Struct(fields) => {
+                let row: Vec<String> = fields
+                    .iter()
+                    .map(|(name, v)| format!("{}: {}", name, v))
+                    .collect();
+                write!(f, "{{{}}}", row.join(", "))
             }
-            AnyType::IntervalDayTime(v) => write!(f, "{}", v),
-            AnyType::IntervalYearMonth(v) => write!(f, "{}", v),
             _ => unimplemented!(),
         }
     }
@@ -299,4 +486,89 @@ mod test {
             format!("{:?}", s.into_series())
         )
     }
+
+    #[test]
+    fn interval_display() {
+        let s = IntervalYearMonthChunked::new_from_opt_slice("", &[Some(14), None]);
+        assert_eq!(
+            r#"Series: interval(year-month)
+[
+	1y 2mo
+	null
+]"#,
+            format!("{:?}", s.into_series())
+        );
+
+        let one_day_and_a_bit = (1i64 << 32) | 3_661_500;
+        let s = IntervalDayTimeChunked::new_from_slice("", &[one_day_and_a_bit]);
+        assert_eq!(
+            r#"Series: interval(daytime)
+[
+	1d 01:01:01.500
+]"#,
+            format!("{:?}", s.into_series())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "pretty")]
+    fn format_options_null_and_max_rows() {
+        let s = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(3), Some(4)]);
+
+        set_format_options(FormatOptions {
+            null: "NA".into(),
+            max_rows: 2,
+            ..Default::default()
+        });
+        let out = format!("{:?}", s.into_series());
+        assert!(out.contains("NA"));
+        assert_eq!(out.matches('\n').count(), 4); // 2 header lines + 2 rows; no newline after ']'
+
+        // restore the default so later tests in this process aren't affected
+        set_format_options(FormatOptions::default());
+    }
+
+    #[test]
+    #[cfg(feature = "pretty")]
+    fn list_series_display() {
+        use arrow::array::PrimitiveBuilder;
+        use arrow::datatypes::Int32Type;
+
+        let values_builder = PrimitiveBuilder::<Int32Type>::new(8);
+        let mut builder = LargeListPrimitiveChunkedBuilder::new("lists", values_builder, 2);
+        builder.append_opt_series(Some(&Series::new("", [1i32, 2, 3, 4].as_ref())));
+        builder.append_opt_series(None);
+        let ca = builder.finish();
+
+        let out = format!("{:?}", ca.into_series());
+        assert!(out.contains("[1, 2, 3, ...]"));
+        assert!(out.contains("null"));
+    }
+
+    #[test]
+    fn timestamp_display_with_tz() {
+        let ts = AnyType::TimeStamp(0, TimeUnit::Second);
+
+        // no tz set: prints the naive (UTC) value
+        assert_eq!(format!("{}", ts), "1970-01-01 00:00:00");
+
+        set_format_options(FormatOptions {
+            tz: Some("UTC".into()),
+            ..Default::default()
+        });
+        assert!(format!("{}", ts).contains("1970-01-01 00:00:00"));
+
+        // an unparseable tz falls back to the naive value instead of erroring, as `safe`
+        // is on by default
+        set_format_options(FormatOptions {
+            tz: Some("Not/AZone".into()),
+            ..Default::default()
+        });
+        let out = format!("{}", ts);
+        assert!(out.contains("1970-01-01 00:00:00"));
+        assert!(out.contains("Not/AZone"));
+
+        // restore the default so later tests in this process aren't affected
+        set_format_options(FormatOptions::default());
+    }
 }
@@ -1,10 +1,13 @@
 use super::hash_join::prepare_hashed_relation;
 use crate::chunked_array::builder::PrimitiveChunkedBuilder;
+use crate::datatypes::AnyType;
 use crate::prelude::*;
 use enum_dispatch::enum_dispatch;
 use num::{Num, NumCast, ToPrimitive, Zero};
 use rayon::prelude::*;
-use std::hash::Hash;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
 fn groupby<T>(a: impl Iterator<Item = T>) -> Vec<(usize, Vec<usize>)>
 where
@@ -65,29 +68,67 @@ impl IntoGroupTuples for Float64Chunked {}
 impl IntoGroupTuples for Float32Chunked {}
 impl IntoGroupTuples for LargeListChunked {}
 
+/// Builds a composite key string from a row's values across all key columns, so
+/// multi-column group keys can be compared by actual value equality. A control
+/// character separates each column's formatted value so e.g. `("ab", "c")` and
+/// `("a", "bc")` can't be concatenated into the same key string.
+fn row_key(columns: &[&Series], i: usize) -> String {
+    columns
+        .iter()
+        .map(|s| format!("{}", s.get(i)))
+        .collect::<Vec<_>>()
+        .join("\u{1}")
+}
+
+/// Groups rows by actual equality of their composite key (every key column's value,
+/// in order), not by a collapsed hash digest -- a hash collision between two different
+/// key tuples would otherwise silently merge them into the same group.
+fn groupby_multiple_columns(columns: &[&Series]) -> Vec<(usize, Vec<usize>)> {
+    let n = columns.first().map(|s| s.len()).unwrap_or(0);
+
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+    for i in 0..n {
+        groups.entry(row_key(columns, i)).or_insert_with(Vec::new).push(i);
+    }
+    groups
+        .into_iter()
+        .map(|(_, indexes)| {
+            let first = indexes[0];
+            (first, indexes)
+        })
+        .collect()
+}
+
 impl DataFrame {
-    /// Group DataFrame using a Series column.
+    /// Group DataFrame using one or more Series columns.
     ///
     /// # Example
     ///
     /// ```
     /// use polars::prelude::*;
     /// fn groupby_sum(df: &DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("column_name")?
+    ///     df.groupby(&["column_name"])?
     ///     .select("agg_column_name")
     ///     .sum()
     /// }
     /// ```
-    pub fn groupby(&self, by: &str) -> Result<GroupBy> {
-        let groups = if let Some(s) = self.column(by) {
-            s.group_tuples()
-        } else {
-            return Err(PolarsError::NotFound);
+    pub fn groupby(&self, by: &[&str]) -> Result<GroupBy> {
+        let columns = by
+            .iter()
+            .map(|&name| {
+                self.column(name)
+                    .ok_or_else(|| PolarsError::NotFound(name.to_string().into()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let groups = match columns.as_slice() {
+            [s] => s.group_tuples(),
+            _ => groupby_multiple_columns(&columns),
         };
 
         Ok(GroupBy {
             df: self,
-            by: by.to_string(),
+            by: by.iter().map(|s| s.to_string()).collect(),
             groups,
             selection: None,
         })
@@ -97,7 +138,7 @@ impl DataFrame {
 #[derive(Debug, Clone)]
 pub struct GroupBy<'a> {
     df: &'a DataFrame,
-    pub by: String,
+    pub by: Vec<String>,
     // [first idx, [other idx]]
     groups: Vec<(usize, Vec<usize>)>,
     selection: Option<String>,
@@ -117,6 +158,194 @@ trait NumericAggSync {
     fn agg_sum(&self, _groups: &Vec<(usize, Vec<usize>)>) -> Series {
         unimplemented!()
     }
+    fn agg_var(&self, _groups: &Vec<(usize, Vec<usize>)>) -> Series {
+        unimplemented!()
+    }
+    fn agg_std(&self, _groups: &Vec<(usize, Vec<usize>)>) -> Series {
+        unimplemented!()
+    }
+    fn agg_n_unique_approx(&self, _groups: &Vec<(usize, Vec<usize>)>) -> Series {
+        unimplemented!()
+    }
+    fn agg_quantile(&self, _groups: &Vec<(usize, Vec<usize>)>, _quantile: f64) -> Series {
+        unimplemented!()
+    }
+}
+
+/// Controls the accuracy/memory trade-off of the t-digest: a centroid's weight is
+/// capped at roughly `4 * n * q * (1 - q) / TDIGEST_DELTA`.
+const TDIGEST_DELTA: f64 = 100.0;
+
+/// A compressed summary of a distribution used to answer approximate quantile queries
+/// in O(1) space relative to the number of observations.
+struct TDigest {
+    // (mean, weight), kept sorted by mean.
+    centroids: Vec<(f64, f64)>,
+    total_weight: f64,
+}
+
+impl TDigest {
+    fn new() -> Self {
+        Self {
+            centroids: Vec::new(),
+            total_weight: 0.,
+        }
+    }
+
+    fn insert(&mut self, x: f64) {
+        self.centroids.push((x, 1.));
+        self.total_weight += 1.;
+        // Compress once in a while so the centroid count stays bounded, instead of
+        // paying the sort/merge cost on every single insert.
+        if self.centroids.len() > (4. * TDIGEST_DELTA) as usize {
+            self.compress();
+        }
+    }
+
+    fn compress(&mut self) {
+        if self.centroids.is_empty() {
+            return;
+        }
+        self.centroids
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let total = self.total_weight;
+        let mut merged = Vec::with_capacity(self.centroids.len());
+        let mut cum_weight = 0.;
+        let (mut mean, mut weight) = self.centroids[0];
+
+        for &(next_mean, next_weight) in &self.centroids[1..] {
+            let q = (cum_weight + weight / 2.) / total;
+            let max_weight = (4. * total * q * (1. - q) / TDIGEST_DELTA).max(1.);
+
+            if weight + next_weight <= max_weight {
+                // merge into the current centroid, weighting the means by their mass
+                mean = (mean * weight + next_mean * next_weight) / (weight + next_weight);
+                weight += next_weight;
+            } else {
+                cum_weight += weight;
+                merged.push((mean, weight));
+                mean = next_mean;
+                weight = next_weight;
+            }
+        }
+        merged.push((mean, weight));
+        self.centroids = merged;
+    }
+
+    /// Linear interpolation between the two centroids surrounding `q * total_weight`.
+    fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+        if self.centroids.len() == 1 {
+            return Some(self.centroids[0].0);
+        }
+
+        let target = q * self.total_weight;
+        let mut cum_weight = 0.;
+
+        for i in 0..self.centroids.len() {
+            let (mean, weight) = self.centroids[i];
+            let next_cum_weight = cum_weight + weight;
+            if target <= cum_weight + weight / 2. || i == self.centroids.len() - 1 {
+                let (prev_mean, prev_weight) = if i == 0 {
+                    self.centroids[0]
+                } else {
+                    self.centroids[i - 1]
+                };
+                // each centroid's mean sits at the midpoint of its weight range
+                let lo_weight = cum_weight - prev_weight / 2.;
+                let hi_weight = cum_weight + weight / 2.;
+                if (hi_weight - lo_weight).abs() < f64::EPSILON {
+                    return Some(mean);
+                }
+                let frac = ((target - lo_weight) / (hi_weight - lo_weight)).clamp(0., 1.);
+                return Some(prev_mean + frac * (mean - prev_mean));
+            }
+            cum_weight = next_cum_weight;
+        }
+        self.centroids.last().map(|&(mean, _)| mean)
+    }
+}
+
+fn tdigest_quantile<T>(ca: &ChunkedArray<T>, idx: &[usize], quantile: f64) -> Option<f64>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    let mut digest = TDigest::new();
+    if let Ok(slice) = ca.cont_slice() {
+        for i in idx {
+            digest.insert(slice[*i].to_f64().unwrap());
+        }
+    } else {
+        let take = unsafe { ca.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+        for opt_v in &take {
+            if let Some(v) = opt_v {
+                digest.insert(v.to_f64().unwrap());
+            }
+        }
+    }
+    digest.quantile(quantile)
+}
+
+/// Number of registers used by the HyperLogLog sketch: `m = 2^HLL_BITS`.
+const HLL_BITS: u32 = 14;
+const HLL_M: usize = 1 << HLL_BITS;
+
+/// Estimate the number of distinct values among `idx` using a HyperLogLog sketch.
+/// This avoids materializing a hash set per group, trading a small, bounded amount of
+/// accuracy (~1%) for O(m) memory regardless of how many values are in the group.
+fn hyperloglog_n_unique<T>(ca: &ChunkedArray<T>, idx: &[usize]) -> u32
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    let mut registers = vec![0u8; HLL_M];
+
+    // Hash on the bit pattern of the f64 representation so integer and float
+    // typed columns share the same canonical hashing path.
+    let mut observe = |v: &T::Native| {
+        let mut hasher = DefaultHasher::new();
+        v.to_f64().unwrap().to_bits().hash(&mut hasher);
+        let h = hasher.finish();
+
+        let register = (h >> (64 - HLL_BITS)) as usize;
+        // rank = number of leading zeros in the remaining bits, plus one
+        let rest = (h << HLL_BITS) | (1 << (HLL_BITS - 1));
+        let rank = (rest.leading_zeros() + 1) as u8;
+        if rank > registers[register] {
+            registers[register] = rank;
+        }
+    };
+
+    if let Ok(slice) = ca.cont_slice() {
+        for i in idx {
+            observe(&slice[*i]);
+        }
+    } else {
+        let take = unsafe { ca.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+        for opt_v in &take {
+            if let Some(v) = opt_v {
+                observe(&v);
+            }
+        }
+    }
+
+    let m = HLL_M as f64;
+    let alpha_m = 0.7213 / (1. + 1.079 / m);
+    let sum_inv: f64 = registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+    let estimate = alpha_m * m * m / sum_inv;
+
+    let zero_registers = registers.iter().filter(|&&r| r == 0).count();
+    let corrected = if estimate <= 2.5 * m && zero_registers > 0 {
+        m * (m / zero_registers as f64).ln()
+    } else {
+        estimate
+    };
+    corrected.round().max(0.) as u32
 }
 
 impl NumericAggSync for BooleanChunked {}
@@ -233,6 +462,155 @@ where
             .collect::<ChunkedArray<T>>()
             .into_series()
     }
+
+    fn agg_var(&self, groups: &Vec<(usize, Vec<usize>)>) -> Series {
+        Series::Float64(
+            groups
+                .par_iter()
+                .map(|(_first, idx)| welford_var(self, idx))
+                .collect(),
+        )
+    }
+
+    fn agg_std(&self, groups: &Vec<(usize, Vec<usize>)>) -> Series {
+        Series::Float64(
+            groups
+                .par_iter()
+                .map(|(_first, idx)| welford_var(self, idx).map(|var| var.sqrt()))
+                .collect(),
+        )
+    }
+
+    fn agg_n_unique_approx(&self, groups: &Vec<(usize, Vec<usize>)>) -> Series {
+        let ca: UInt32Chunked = groups
+            .par_iter()
+            .map(|(_first, idx)| Some(hyperloglog_n_unique(self, idx)))
+            .collect();
+        Series::UInt32(ca)
+    }
+
+    fn agg_quantile(&self, groups: &Vec<(usize, Vec<usize>)>, quantile: f64) -> Series {
+        Series::Float64(
+            groups
+                .par_iter()
+                .map(|(_first, idx)| tdigest_quantile(self, idx, quantile))
+                .collect(),
+        )
+    }
+}
+
+/// Numerically stable variance over a group's index slice using Welford's online algorithm.
+/// Returns `None` (null) when fewer than 2 values are present.
+fn welford_var<T>(ca: &ChunkedArray<T>, idx: &[usize]) -> Option<f64>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    let mut count = 0u64;
+    let mut mean = 0f64;
+    let mut m2 = 0f64;
+
+    let mut accumulate = |x: f64| {
+        count += 1;
+        let delta = x - mean;
+        mean += delta / count as f64;
+        m2 += delta * (x - mean);
+    };
+
+    if let Ok(slice) = ca.cont_slice() {
+        for i in idx {
+            accumulate(slice[*i].to_f64().unwrap());
+        }
+    } else {
+        let take = unsafe { ca.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+        for opt_v in &take {
+            if let Some(v) = opt_v {
+                accumulate(v.to_f64().unwrap());
+            }
+        }
+    }
+
+    if count < 2 {
+        None
+    } else {
+        Some(m2 / (count - 1) as f64)
+    }
+}
+
+/// A user-supplied reduction for `GroupBy::agg_with`, for rollups that don't fit the
+/// fixed methods on `NumericAggSync` (weighted means, mode, domain-specific scores, ...).
+/// A group's values are folded into a `State` via `update`, partial states computed on
+/// disjoint slices are combined with `merge`, and the fully reduced state is turned into
+/// a scalar with `finish` -- mirroring the accumulator split most query engines expose
+/// for UDAFs, so the reduction stays parallel without the crate having to know about it.
+pub trait GroupByAccumulator: Send + Sync {
+    type State: Send;
+
+    /// Create the identity/empty state.
+    fn init(&self) -> Self::State;
+
+    /// Fold a slice of a group's values into a partial state.
+    fn update(&self, state: &mut Self::State, s: &Series);
+
+    /// Combine two partial states that were computed independently.
+    fn merge(&self, a: Self::State, b: Self::State) -> Self::State;
+
+    /// Turn the fully reduced state for one group into its scalar output.
+    fn finish(&self, state: Self::State) -> AnyType<'static>;
+}
+
+/// Build a `Series` from per-group scalars returned by a `GroupByAccumulator`. The
+/// concrete dtype is inferred from the first non-null value, since the accumulator
+/// itself doesn't declare one up front.
+fn any_values_to_series(name: &str, values: Vec<AnyType<'static>>) -> Series {
+    macro_rules! build_primitive {
+        ($ca_type:ty, $variant:ident) => {{
+            let ca: $ca_type = values
+                .iter()
+                .map(|v| match v {
+                    AnyType::$variant(x) => Some(*x),
+                    _ => None,
+                })
+                .collect();
+            Series::$variant(ca)
+        }};
+    }
+
+    let mut series = match values.iter().find(|v| !matches!(v, AnyType::Null)) {
+        Some(AnyType::Float32(_)) => build_primitive!(Float32Chunked, Float32),
+        Some(AnyType::Int8(_)) => build_primitive!(Int8Chunked, Int8),
+        Some(AnyType::Int16(_)) => build_primitive!(Int16Chunked, Int16),
+        Some(AnyType::Int32(_)) => build_primitive!(Int32Chunked, Int32),
+        Some(AnyType::Int64(_)) => build_primitive!(Int64Chunked, Int64),
+        Some(AnyType::UInt8(_)) => build_primitive!(UInt8Chunked, UInt8),
+        Some(AnyType::UInt16(_)) => build_primitive!(UInt16Chunked, UInt16),
+        Some(AnyType::UInt32(_)) => build_primitive!(UInt32Chunked, UInt32),
+        Some(AnyType::UInt64(_)) => build_primitive!(UInt64Chunked, UInt64),
+        Some(AnyType::Boolean(_)) => {
+            let ca: BooleanChunked = values
+                .iter()
+                .map(|v| match v {
+                    AnyType::Boolean(b) => Some(*b),
+                    _ => None,
+                })
+                .collect();
+            Series::Boolean(ca)
+        }
+        Some(AnyType::Utf8(_)) => {
+            let ca: Utf8Chunked = values
+                .iter()
+                .map(|v| match v {
+                    AnyType::Utf8(s) => Some(*s),
+                    _ => None,
+                })
+                .collect();
+            Series::Utf8(ca)
+        }
+        // Default to Float64, the same fallback the built-in numeric aggregations use.
+        _ => build_primitive!(Float64Chunked, Float64),
+    };
+    series.rename(name);
+    series
 }
 
 impl<'a> GroupBy<'a> {
@@ -241,26 +619,41 @@ impl<'a> GroupBy<'a> {
         self
     }
 
-    fn keys(&self) -> Series {
-        unsafe {
-            self.df.f_column(&self.by).take_iter_unchecked(
-                self.groups.iter().map(|(idx, _)| *idx),
-                Some(self.groups.len()),
-            )
-        }
+    /// Gather the key column(s), one Series per `by` column, by the first index of
+    /// each group.
+    fn keys(&self) -> Vec<Series> {
+        self.by
+            .iter()
+            .map(|name| unsafe {
+                self.df.f_column(name).take_iter_unchecked(
+                    self.groups.iter().map(|(idx, _)| *idx),
+                    Some(self.groups.len()),
+                )
+            })
+            .collect()
     }
 
-    fn prepare_agg(&self) -> Result<(&String, Series, &Series)> {
+    fn prepare_agg(&self) -> Result<(&String, Vec<Series>, &Series)> {
         let name = match &self.selection {
             Some(name) => name,
             None => return Err(PolarsError::NoSelection),
         };
 
         let keys = self.keys();
-        let agg_col = self.df.column(name).ok_or(PolarsError::NotFound)?;
+        let agg_col = self
+            .df
+            .column(name)
+            .ok_or_else(|| PolarsError::NotFound(name.to_string().into()))?;
         Ok((name, keys, agg_col))
     }
 
+    /// Assemble the key columns and the aggregated column into the final DataFrame.
+    fn finish_agg(keys: Vec<Series>, agg: Series) -> Result<DataFrame> {
+        let mut columns = keys;
+        columns.push(agg);
+        DataFrame::new(columns)
+    }
+
     /// Aggregate grouped series and compute the mean per group.
     pub fn mean(&self) -> Result<DataFrame> {
         let (name, keys, agg_col) = self.prepare_agg()?;
@@ -268,7 +661,7 @@ impl<'a> GroupBy<'a> {
 
         let mut agg = agg_col.agg_mean(&self.groups);
         agg.rename(&new_name);
-        DataFrame::new(vec![keys, agg])
+        Self::finish_agg(keys, agg)
     }
 
     /// Aggregate grouped series and compute the sum per group.
@@ -277,7 +670,7 @@ impl<'a> GroupBy<'a> {
         let new_name = format!["{}_sum", name];
         let mut agg = agg_col.agg_sum(&self.groups);
         agg.rename(&new_name);
-        DataFrame::new(vec![keys, agg])
+        Self::finish_agg(keys, agg)
     }
 
     /// Aggregate grouped series and compute the minimal value per group.
@@ -286,7 +679,7 @@ impl<'a> GroupBy<'a> {
         let new_name = format!["{}_min", name];
         let mut agg = apply_method_numeric_series!(agg_col, agg_min, &self.groups);
         agg.rename(&new_name);
-        DataFrame::new(vec![keys, agg])
+        Self::finish_agg(keys, agg)
     }
 
     /// Aggregate grouped series and compute the maximum value per group.
@@ -295,7 +688,89 @@ impl<'a> GroupBy<'a> {
         let new_name = format!["{}_max", name];
         let mut agg = agg_col.agg_max(&self.groups);
         agg.rename(&new_name);
-        DataFrame::new(vec![keys, agg])
+        Self::finish_agg(keys, agg)
+    }
+
+    /// Aggregate grouped series and compute the sample variance per group.
+    pub fn var(&self) -> Result<DataFrame> {
+        let (name, keys, agg_col) = self.prepare_agg()?;
+        let new_name = format!["{}_var", name];
+        let mut agg = agg_col.agg_var(&self.groups);
+        agg.rename(&new_name);
+        Self::finish_agg(keys, agg)
+    }
+
+    /// Aggregate grouped series and compute the sample standard deviation per group.
+    pub fn std(&self) -> Result<DataFrame> {
+        let (name, keys, agg_col) = self.prepare_agg()?;
+        let new_name = format!["{}_std", name];
+        let mut agg = agg_col.agg_std(&self.groups);
+        agg.rename(&new_name);
+        Self::finish_agg(keys, agg)
+    }
+
+    /// Aggregate grouped series and compute the approximate number of unique values per
+    /// group using a HyperLogLog sketch. This is much cheaper than an exact `n_unique`
+    /// for high-cardinality group keys, at the cost of ~1% relative error.
+    pub fn n_unique_approx(&self) -> Result<DataFrame> {
+        let (name, keys, agg_col) = self.prepare_agg()?;
+        let new_name = format!["{}_n_unique_approx", name];
+        let mut agg = agg_col.agg_n_unique_approx(&self.groups);
+        agg.rename(&new_name);
+        Self::finish_agg(keys, agg)
+    }
+
+    /// Aggregate grouped series and compute an approximate quantile per group using a
+    /// t-digest. Memory is bounded to O(delta) per group regardless of group size.
+    pub fn quantile(&self, quantile: f64) -> Result<DataFrame> {
+        let (name, keys, agg_col) = self.prepare_agg()?;
+        let new_name = format!["{}_quantile", name];
+        let mut agg = agg_col.agg_quantile(&self.groups, quantile);
+        agg.rename(&new_name);
+        Self::finish_agg(keys, agg)
+    }
+
+    /// Aggregate grouped series and compute the approximate median per group.
+    pub fn median(&self) -> Result<DataFrame> {
+        let (name, keys, agg_col) = self.prepare_agg()?;
+        let new_name = format!["{}_median", name];
+        let mut agg = agg_col.agg_quantile(&self.groups, 0.5);
+        agg.rename(&new_name);
+        Self::finish_agg(keys, agg)
+    }
+
+    /// Aggregate grouped series with a user-supplied `GroupByAccumulator`, for rollups
+    /// that the fixed `NumericAggSync` methods don't cover. Each group's index slice is
+    /// split across threads and reduced independently, and the partial states are then
+    /// combined with `GroupByAccumulator::merge`, so the custom aggregation stays
+    /// parallel just like the built-in ones.
+    pub fn agg_with<A: GroupByAccumulator>(&self, acc: &A) -> Result<DataFrame> {
+        let (name, keys, agg_col) = self.prepare_agg()?;
+        let new_name = format!["{}_agg", name];
+
+        let n_threads = rayon::current_num_threads().max(1);
+        let values: Vec<AnyType<'static>> = self
+            .groups
+            .par_iter()
+            .map(|(_first, idx)| {
+                let chunk_size = (idx.len() / n_threads).max(1);
+                let state = idx
+                    .par_chunks(chunk_size)
+                    .map(|chunk| {
+                        let s = unsafe {
+                            agg_col.take_iter_unchecked(chunk.iter().copied(), Some(chunk.len()))
+                        };
+                        let mut state = acc.init();
+                        acc.update(&mut state, &s);
+                        state
+                    })
+                    .reduce(|| acc.init(), |a, b| acc.merge(a, b));
+                acc.finish(state)
+            })
+            .collect();
+
+        let agg = any_values_to_series(&new_name, values);
+        Self::finish_agg(keys, agg)
     }
 
     /// Aggregate grouped series and compute the number of values per group.
@@ -311,7 +786,7 @@ impl<'a> GroupBy<'a> {
         }
         let ca = builder.finish();
         let agg = Series::UInt32(ca);
-        DataFrame::new(vec![keys, agg])
+        Self::finish_agg(keys, agg)
     }
 }
 
@@ -328,23 +803,153 @@ mod test {
 
         println!(
             "{:?}",
-            df.groupby("days").unwrap().select("temp").count().unwrap()
+            df.groupby(&["days"]).unwrap().select("temp").count().unwrap()
+        );
+        println!(
+            "{:?}",
+            df.groupby(&["days"]).unwrap().select("temp").mean().unwrap()
         );
         println!(
             "{:?}",
-            df.groupby("days").unwrap().select("temp").mean().unwrap()
+            df.groupby(&["days"]).unwrap().select("temp").sum().unwrap()
         );
         println!(
             "{:?}",
-            df.groupby("days").unwrap().select("temp").sum().unwrap()
+            df.groupby(&["days"]).unwrap().select("temp").min().unwrap()
         );
         println!(
             "{:?}",
-            df.groupby("days").unwrap().select("temp").min().unwrap()
+            df.groupby(&["days"]).unwrap().select("temp").max().unwrap()
         );
         println!(
             "{:?}",
-            df.groupby("days").unwrap().select("temp").max().unwrap()
+            df.groupby(&["days"]).unwrap().select("temp").std().unwrap()
         );
     }
+
+    #[test]
+    fn test_group_by_var() {
+        let s0 = Series::new("days", ["mo", "mo", "mo", "tue"].as_ref());
+        let s1 = Series::new("temp", [2, 4, 6, 10].as_ref());
+        let df = DataFrame::new(vec![s0, s1]).unwrap();
+
+        let var_df = df.groupby(&["days"]).unwrap().select("temp").var().unwrap();
+        let values = Vec::from(var_df.column("temp_var").unwrap().f64().unwrap());
+
+        // mo: values 2, 4, 6 -> mean 4, variance = ((2-4)^2 + 0 + (6-4)^2) / (3 - 1) = 4
+        assert!(values.iter().any(|v| *v == Some(4.0)));
+        // tue is a single-value group, so it has no degrees of freedom and the variance is null
+        assert!(values.iter().any(|v| v.is_none()));
+    }
+
+    #[test]
+    fn test_group_by_n_unique_approx() {
+        let s0 = Series::new("days", vec!["mo"; 1000].as_ref());
+        let s1 = Series::new("temp", (0..1000).collect::<Vec<_>>().as_ref());
+        let df = DataFrame::new(vec![s0, s1]).unwrap();
+
+        let approx_df = df
+            .groupby(&["days"])
+            .unwrap()
+            .select("temp")
+            .n_unique_approx()
+            .unwrap();
+        let count = approx_df
+            .column("temp_n_unique_approx")
+            .unwrap()
+            .u32()
+            .unwrap()
+            .get(0)
+            .unwrap();
+        // HyperLogLog with b=14 is within ~1% of the true count for well-behaved inputs
+        assert!((900..1100).contains(&count));
+    }
+
+    #[test]
+    fn test_group_by_agg_with() {
+        struct SumAccumulator;
+
+        impl GroupByAccumulator for SumAccumulator {
+            type State = i64;
+
+            fn init(&self) -> i64 {
+                0
+            }
+            fn update(&self, state: &mut i64, s: &Series) {
+                *state += s.i32().unwrap().sum().unwrap_or(0) as i64;
+            }
+            fn merge(&self, a: i64, b: i64) -> i64 {
+                a + b
+            }
+            fn finish(&self, state: i64) -> AnyType<'static> {
+                AnyType::Int64(state)
+            }
+        }
+
+        let s0 = Series::new("days", ["mo", "mo", "mo", "tue"].as_ref());
+        let s1 = Series::new("temp", [2, 4, 6, 10].as_ref());
+        let df = DataFrame::new(vec![s0, s1]).unwrap();
+
+        let sum_df = df
+            .groupby(&["days"])
+            .unwrap()
+            .select("temp")
+            .agg_with(&SumAccumulator)
+            .unwrap();
+        let values = Vec::from(sum_df.column("temp_agg").unwrap().i64().unwrap());
+
+        assert!(values.iter().any(|v| *v == Some(12))); // mo: 2 + 4 + 6
+        assert!(values.iter().any(|v| *v == Some(10))); // tue: 10
+    }
+
+    #[test]
+    fn test_group_by_median() {
+        let s0 = Series::new("days", ["mo", "mo", "mo", "mo", "mo"].as_ref());
+        let s1 = Series::new("temp", [1, 2, 3, 4, 5].as_ref());
+        let df = DataFrame::new(vec![s0, s1]).unwrap();
+
+        let median_df = df.groupby(&["days"]).unwrap().select("temp").median().unwrap();
+        let median = median_df
+            .column("temp_median")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .get(0)
+            .unwrap();
+        assert!((median - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_group_by_multiple_keys() {
+        let s0 = Series::new("days", ["mo", "mo", "mo", "tue"].as_ref());
+        let s1 = Series::new("shift", ["am", "am", "pm", "am"].as_ref());
+        let s2 = Series::new("temp", [2, 4, 6, 10].as_ref());
+        let df = DataFrame::new(vec![s0, s1, s2]).unwrap();
+
+        let sum_df = df
+            .groupby(&["days", "shift"])
+            .unwrap()
+            .select("temp")
+            .sum()
+            .unwrap();
+
+        assert_eq!(sum_df.width(), 3);
+        let days = Vec::from(sum_df.column("days").unwrap().utf8().unwrap());
+        let shifts = Vec::from(sum_df.column("shift").unwrap().utf8().unwrap());
+        let sums = Vec::from(sum_df.column("temp_sum").unwrap().i32().unwrap());
+
+        let mo_am = days
+            .iter()
+            .zip(shifts.iter())
+            .position(|(d, s)| *d == Some("mo") && *s == Some("am"))
+            .unwrap();
+        assert_eq!(sums[mo_am], Some(6)); // mo/am: 2 + 4
+
+        let mo_pm = days
+            .iter()
+            .zip(shifts.iter())
+            .position(|(d, s)| *d == Some("mo") && *s == Some("pm"))
+            .unwrap();
+        assert_eq!(sums[mo_pm], Some(6)); // mo/pm: 6
+    }
 }
@@ -4,10 +4,65 @@ use arrow::datatypes::{Field, Schema};
 use std::fmt;
 use std::rc::Rc;
 
+/// The name a qualified column resolves against in a schema: `"relation.name"` when
+/// qualified, or the bare `name` otherwise. Kept as one helper so `Expr::Column`'s
+/// `get_type`/`to_field` and the `"table.col"` parsing in `impl From<&str> for Expr`
+/// agree on exactly the same qualification format.
+fn qualified_name(relation: &Option<Rc<String>>, name: &str) -> String {
+    match relation {
+        Some(relation) => format!("{}.{}", relation, name),
+        None => name.to_string(),
+    }
+}
+
+/// Merge schemas from multiple relations into one, prefixing every field name with its
+/// source relation (`"table.col"`). This is the schema-side counterpart to a qualified
+/// `Expr::Column`: once a join's inputs are merged this way, two columns that share a
+/// bare name (`df.a` and `other.a`) resolve to distinct fields instead of colliding.
+pub fn merge_schemas_with_relations(relations: &[(&str, &Schema)]) -> Schema {
+    let fields = relations
+        .iter()
+        .flat_map(|(relation, schema)| {
+            schema.fields().iter().map(move |f| {
+                Field::new(
+                    &format!("{}.{}", relation, f.name()),
+                    f.data_type().clone(),
+                    f.is_nullable(),
+                )
+            })
+        })
+        .collect();
+    Schema::new(fields)
+}
+
+/// Protocol for a user-defined aggregate that accumulates incrementally instead of
+/// seeing its whole group as one `&Series` up front (see [`Expr::Udaf`]). `merge` lets
+/// two partial accumulators (e.g. from two chunks processed independently) combine into
+/// one, the same shape a parallel groupby executor would need to fold per-thread partials
+/// back together.
+pub trait Accumulator {
+    /// Fold another batch of values from this accumulator's group into its state.
+    fn update(&mut self, incoming: &Series);
+
+    /// Fold another accumulator's state (e.g. from a different chunk of the same group)
+    /// into this one.
+    fn merge(&mut self, other: &dyn Accumulator);
+
+    /// Produce the final scalar result for this group.
+    fn finalize(&self) -> Series;
+}
+
 #[derive(Clone)]
 pub enum Expr {
     Alias(Box<Expr>, Rc<String>),
-    Column(Rc<String>),
+    /// A column reference, optionally qualified by the relation (table/input) it comes
+    /// from -- e.g. `df.a` vs `other.a` -- so two joined inputs that share a column name
+    /// resolve unambiguously. `relation: None` behaves exactly like the old unqualified
+    /// `Column(Rc<String>)` did.
+    Column {
+        relation: Option<Rc<String>>,
+        name: Rc<String>,
+    },
     Literal(ScalarValue),
     BinaryExpr {
         left: Box<Expr>,
@@ -32,6 +87,58 @@ pub enum Expr {
                        //     return_type: ArrowDataType,
                        // },
                        // Wildcard
+    AggMax(Box<Expr>),
+    AggSum(Box<Expr>),
+    AggMean(Box<Expr>),
+    AggCount(Box<Expr>),
+    AggFirst(Box<Expr>),
+    AggLast(Box<Expr>),
+    /// The order statistic at fractional rank `quantile * (n - 1)` within each group,
+    /// the same semantics as `ChunkQuantile::quantile`.
+    AggQuantile {
+        expr: Box<Expr>,
+        quantile: f64,
+    },
+    /// A user-defined aggregate function, for rollups that don't fit the built-in
+    /// `Agg*` variants. `function` is run per group by the executor, the same way
+    /// `GroupByAccumulator` is driven eagerly by `GroupBy::agg_with`.
+    AggUserDefined {
+        expr: Box<Expr>,
+        name: Rc<String>,
+        function: Rc<dyn Fn(&Series) -> Series>,
+    },
+    /// A user-defined aggregate driven incrementally via an [`Accumulator`], for
+    /// rollups too large to evaluate via a single `&Series` callback (e.g. streaming
+    /// one chunk at a time, or merging partial results computed in parallel). `new_acc`
+    /// is called once per group to get a fresh `Accumulator`, which the executor then
+    /// drives through `update`/`merge`/`finalize`.
+    Udaf {
+        expr: Box<Expr>,
+        name: Rc<String>,
+        new_acc: Rc<dyn Fn() -> Box<dyn Accumulator>>,
+    },
+    Contains {
+        expr: Box<Expr>,
+        pat: Rc<String>,
+    },
+    StartsWith {
+        expr: Box<Expr>,
+        pat: Rc<String>,
+    },
+    EndsWith {
+        expr: Box<Expr>,
+        pat: Rc<String>,
+    },
+    StrLengths(Box<Expr>),
+    ToLowercase(Box<Expr>),
+    ToUppercase(Box<Expr>),
+    /// `CASE WHEN <pred> THEN <then> ... ELSE <otherwise> END`. Each predicate is
+    /// evaluated to a boolean mask and used to pick from its `then` branch; a row that
+    /// matches none of them falls back to `otherwise`, or null if there isn't one.
+    Case {
+        when_then: Vec<(Expr, Expr)>,
+        otherwise: Option<Box<Expr>>,
+    },
 }
 
 impl Expr {
@@ -40,7 +147,10 @@ impl Expr {
         use Expr::*;
         match self {
             Alias(expr, ..) => expr.get_type(schema),
-            Column(name) => Ok(schema.field_with_name(name)?.data_type().clone()),
+            Column { relation, name } => Ok(schema
+                .field_with_name(&qualified_name(relation, name))?
+                .data_type()
+                .clone()),
             Literal(sv) => Ok(sv.get_datatype()),
             BinaryExpr { left, op, right } => match op {
                 Operator::Not
@@ -65,6 +175,46 @@ impl Expr {
             IsNotNull(_) => Ok(ArrowDataType::Boolean),
             Sort { expr, .. } => expr.get_type(schema),
             AggMin(expr) => expr.get_type(schema),
+            AggMax(expr) => expr.get_type(schema),
+            AggSum(expr) => expr.get_type(schema),
+            AggFirst(expr) => expr.get_type(schema),
+            AggLast(expr) => expr.get_type(schema),
+            AggMean(expr) => {
+                let _ = expr.get_type(schema)?;
+                Ok(ArrowDataType::Float64)
+            }
+            AggCount(expr) => {
+                let _ = expr.get_type(schema)?;
+                Ok(ArrowDataType::UInt32)
+            }
+            AggQuantile { expr, .. } => expr.get_type(schema),
+            AggUserDefined { expr, .. } => expr.get_type(schema),
+            Udaf { expr, .. } => expr.get_type(schema),
+            Contains { .. } | StartsWith { .. } | EndsWith { .. } => Ok(ArrowDataType::Boolean),
+            StrLengths(_) => Ok(ArrowDataType::UInt32),
+            ToLowercase(_) | ToUppercase(_) => Ok(ArrowDataType::Utf8),
+            Case {
+                when_then,
+                otherwise,
+            } => {
+                let mut dtype = None;
+                for (_, then) in when_then {
+                    dtype = match dtype {
+                        None => Some(then.get_type(schema)?),
+                        Some(dtype) => Some(get_supertype(&dtype, &then.get_type(schema)?)?),
+                    };
+                }
+                if let Some(otherwise) = otherwise {
+                    let otherwise_type = otherwise.get_type(schema)?;
+                    dtype = Some(match dtype {
+                        None => otherwise_type,
+                        Some(dtype) => get_supertype(&dtype, &otherwise_type)?,
+                    });
+                }
+                dtype.ok_or_else(|| {
+                    PolarsError::InvalidOperation("case expression has no branches".into())
+                })
+            }
         }
     }
 
@@ -73,8 +223,10 @@ impl Expr {
         use Expr::*;
         match self {
             Alias(expr, name) => Ok(Field::new(name, expr.get_type(schema)?, true)),
-            Column(name) => {
-                let field = schema.field_with_name(name).map(|f| f.clone())?;
+            Column { relation, name } => {
+                let field = schema
+                    .field_with_name(&qualified_name(relation, name))
+                    .map(|f| f.clone())?;
                 Ok(field)
             }
             Literal(sv) => Ok(Field::new("lit", sv.get_datatype(), true)),
@@ -96,6 +248,115 @@ impl Expr {
                     field.is_nullable(),
                 ))
             }
+            AggMax(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_max", field.name()),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                ))
+            }
+            AggSum(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_sum", field.name()),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                ))
+            }
+            AggFirst(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_first", field.name()),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                ))
+            }
+            AggLast(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_last", field.name()),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                ))
+            }
+            AggMean(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_mean", field.name()),
+                    ArrowDataType::Float64,
+                    field.is_nullable(),
+                ))
+            }
+            AggCount(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_count", field.name()),
+                    ArrowDataType::UInt32,
+                    field.is_nullable(),
+                ))
+            }
+            AggQuantile { expr, .. } => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_quantile", field.name()),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                ))
+            }
+            AggUserDefined { expr, name, .. } => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(name, field.data_type().clone(), field.is_nullable()))
+            }
+            Udaf { expr, name, .. } => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(name, field.data_type().clone(), field.is_nullable()))
+            }
+            Contains { expr, .. } => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_contains", field.name()),
+                    ArrowDataType::Boolean,
+                    field.is_nullable(),
+                ))
+            }
+            StartsWith { expr, .. } => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_starts_with", field.name()),
+                    ArrowDataType::Boolean,
+                    field.is_nullable(),
+                ))
+            }
+            EndsWith { expr, .. } => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_ends_with", field.name()),
+                    ArrowDataType::Boolean,
+                    field.is_nullable(),
+                ))
+            }
+            StrLengths(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    &format!("{}_length", field.name()),
+                    ArrowDataType::UInt32,
+                    field.is_nullable(),
+                ))
+            }
+            ToLowercase(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name(), ArrowDataType::Utf8, field.is_nullable()))
+            }
+            ToUppercase(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name(), ArrowDataType::Utf8, field.is_nullable()))
+            }
+            Case { .. } => {
+                // Any row not covered by a `when` and without an `otherwise` becomes
+                // null, so the field is always nullable regardless of the branches.
+                Ok(Field::new("case", self.get_type(schema)?, true))
+            }
         }
     }
 }
@@ -105,7 +366,7 @@ impl fmt::Debug for Expr {
         use Expr::*;
         match self {
             Alias(expr, name) => write!(f, "{:?} AS {}", expr, name),
-            Column(name) => write!(f, "COLUMN {}", name),
+            Column { relation, name } => write!(f, "COLUMN {}", qualified_name(relation, name)),
             Literal(v) => write!(f, "{:?}", v),
             BinaryExpr { left, op, right } => write!(f, "{:?} {:?} {:?}", left, op, right),
             Not(expr) => write!(f, "NOT {:?}", expr),
@@ -116,6 +377,40 @@ impl fmt::Debug for Expr {
                 false => write!(f, "{:?} ASC", expr),
             },
             AggMin(expr) => write!(f, "AGGREGATE MIN {:?}", expr),
+            AggMax(expr) => write!(f, "AGGREGATE MAX {:?}", expr),
+            AggSum(expr) => write!(f, "AGGREGATE SUM {:?}", expr),
+            AggMean(expr) => write!(f, "AGGREGATE MEAN {:?}", expr),
+            AggCount(expr) => write!(f, "AGGREGATE COUNT {:?}", expr),
+            AggFirst(expr) => write!(f, "AGGREGATE FIRST {:?}", expr),
+            AggLast(expr) => write!(f, "AGGREGATE LAST {:?}", expr),
+            AggQuantile { expr, quantile } => {
+                write!(f, "AGGREGATE QUANTILE({}) {:?}", quantile, expr)
+            }
+            AggUserDefined { expr, name, .. } => {
+                write!(f, "AGGREGATE UDF {} {:?}", name, expr)
+            }
+            Udaf { expr, name, .. } => {
+                write!(f, "AGGREGATE UDAF {} {:?}", name, expr)
+            }
+            Contains { expr, pat } => write!(f, "{:?}.contains({})", expr, pat),
+            StartsWith { expr, pat } => write!(f, "{:?}.starts_with({})", expr, pat),
+            EndsWith { expr, pat } => write!(f, "{:?}.ends_with({})", expr, pat),
+            StrLengths(expr) => write!(f, "{:?}.str_lengths()", expr),
+            ToLowercase(expr) => write!(f, "{:?}.to_lowercase()", expr),
+            ToUppercase(expr) => write!(f, "{:?}.to_uppercase()", expr),
+            Case {
+                when_then,
+                otherwise,
+            } => {
+                write!(f, "CASE")?;
+                for (when, then) in when_then {
+                    write!(f, " WHEN {:?} THEN {:?}", when, then)?;
+                }
+                if let Some(otherwise) = otherwise {
+                    write!(f, " ELSE {:?}", otherwise)?;
+                }
+                write!(f, " END")
+            }
         }
     }
 }
@@ -183,11 +478,134 @@ impl Expr {
     pub fn agg_min(self) -> Self {
         Expr::AggMin(Box::new(self))
     }
+
+    /// Reduce column to maximal value.
+    pub fn agg_max(self) -> Self {
+        Expr::AggMax(Box::new(self))
+    }
+
+    /// Reduce column to the sum of its values.
+    pub fn agg_sum(self) -> Self {
+        Expr::AggSum(Box::new(self))
+    }
+
+    /// Reduce column to its mean value.
+    pub fn agg_mean(self) -> Self {
+        Expr::AggMean(Box::new(self))
+    }
+
+    /// Reduce column to the number of values in it.
+    pub fn agg_count(self) -> Self {
+        Expr::AggCount(Box::new(self))
+    }
+
+    /// Reduce column to its first value.
+    pub fn agg_first(self) -> Self {
+        Expr::AggFirst(Box::new(self))
+    }
+
+    /// Reduce column to its last value.
+    pub fn agg_last(self) -> Self {
+        Expr::AggLast(Box::new(self))
+    }
+
+    /// Reduce column to the order statistic at fractional rank `quantile * (n - 1)`.
+    pub fn agg_quantile(self, quantile: f64) -> Self {
+        Expr::AggQuantile {
+            expr: Box::new(self),
+            quantile,
+        }
+    }
+
+    /// Reduce a group to a single value with a user-supplied function, for rollups
+    /// that don't fit the built-in `agg_*` methods (weighted means, mode, ...).
+    pub fn agg_udf(self, name: &str, function: impl Fn(&Series) -> Series + 'static) -> Self {
+        Expr::AggUserDefined {
+            expr: Box::new(self),
+            name: Rc::new(name.into()),
+            function: Rc::new(function),
+        }
+    }
+
+    /// Reduce a group with a custom [`Accumulator`], for rollups that need incremental
+    /// `update`/`merge` instead of seeing the whole group as one `&Series` up front.
+    /// `new_acc` is called once per group to obtain a fresh accumulator.
+    pub fn agg_udaf(
+        self,
+        name: &str,
+        new_acc: impl Fn() -> Box<dyn Accumulator> + 'static,
+    ) -> Self {
+        Expr::Udaf {
+            expr: Box::new(self),
+            name: Rc::new(name.into()),
+            new_acc: Rc::new(new_acc),
+        }
+    }
+
+    /// Check if a Utf8 column contains the given pattern.
+    pub fn contains(self, pat: &str) -> Self {
+        Expr::Contains {
+            expr: Box::new(self),
+            pat: Rc::new(pat.into()),
+        }
+    }
+
+    /// Check if a Utf8 column starts with the given pattern.
+    pub fn starts_with(self, pat: &str) -> Self {
+        Expr::StartsWith {
+            expr: Box::new(self),
+            pat: Rc::new(pat.into()),
+        }
+    }
+
+    /// Check if a Utf8 column ends with the given pattern.
+    pub fn ends_with(self, pat: &str) -> Self {
+        Expr::EndsWith {
+            expr: Box::new(self),
+            pat: Rc::new(pat.into()),
+        }
+    }
+
+    /// Get the length in bytes of every string in a Utf8 column.
+    pub fn str_lengths(self) -> Self {
+        Expr::StrLengths(Box::new(self))
+    }
+
+    /// Lowercase every string in a Utf8 column.
+    pub fn to_lowercase(self) -> Self {
+        Expr::ToLowercase(Box::new(self))
+    }
+
+    /// Uppercase every string in a Utf8 column.
+    pub fn to_uppercase(self) -> Self {
+        Expr::ToUppercase(Box::new(self))
+    }
 }
 
-/// Create a Colum Expression based on a column name.
+/// Create an unqualified Column Expression based on a column name. Use
+/// `Expr::from("table.col")` for a qualified reference.
 pub fn col(name: &str) -> Expr {
-    Expr::Column(Rc::new(name.to_owned()))
+    Expr::Column {
+        relation: None,
+        name: Rc::new(name.to_owned()),
+    }
+}
+
+impl From<&str> for Expr {
+    /// Parses `"table.col"` into a qualified column reference (`relation: Some("table")`),
+    /// or a bare `"col"` into an unqualified one -- the same shape `col()` produces.
+    fn from(s: &str) -> Self {
+        match s.split_once('.') {
+            Some((relation, name)) => Expr::Column {
+                relation: Some(Rc::new(relation.to_owned())),
+                name: Rc::new(name.to_owned()),
+            },
+            None => Expr::Column {
+                relation: None,
+                name: Rc::new(s.to_owned()),
+            },
+        }
+    }
 }
 
 pub trait Literal {
@@ -236,3 +654,89 @@ pub fn lit<L: Literal>(t: L) -> Expr {
 pub fn not(expr: Expr) -> Expr {
     Expr::Not(Box::new(expr))
 }
+
+/// Builder returned by [`when`]/[`Then::when`], waiting for its `.then(..)` branch.
+/// Carries every `WHEN .. THEN ..` branch accumulated so far, so chaining another
+/// `.when(..).then(..)` never loses earlier branches.
+pub struct When {
+    when_then: Vec<(Expr, Expr)>,
+    predicate: Expr,
+}
+
+/// Builder accumulating `WHEN .. THEN ..` branches, waiting for more `.when(..)` or a
+/// final `.otherwise(..)` to produce the `Expr::Case`.
+pub struct Then {
+    when_then: Vec<(Expr, Expr)>,
+}
+
+impl When {
+    /// The value to produce when `predicate` is true.
+    pub fn then(self, expr: Expr) -> Then {
+        let mut when_then = self.when_then;
+        when_then.push((self.predicate, expr));
+        Then { when_then }
+    }
+}
+
+impl Then {
+    /// Add another `WHEN .. THEN ..` branch, tried if none of the earlier ones matched.
+    pub fn when(self, predicate: Expr) -> When {
+        When {
+            when_then: self.when_then,
+            predicate,
+        }
+    }
+
+    /// Finish the `CASE` expression with a fallback for rows matching no branch; the
+    /// result is null on those rows if `otherwise` isn't called.
+    pub fn otherwise(self, expr: Expr) -> Expr {
+        Expr::Case {
+            when_then: self.when_then,
+            otherwise: Some(Box::new(expr)),
+        }
+    }
+
+    /// Finish the `CASE` expression without a fallback: rows matching no branch are null.
+    pub fn end(self) -> Expr {
+        Expr::Case {
+            when_then: self.when_then,
+            otherwise: None,
+        }
+    }
+}
+
+/// Start a `CASE WHEN <predicate> THEN ..` expression, e.g.
+/// `when(col("a").gt(lit(0))).then(lit(1)).otherwise(lit(-1))`.
+pub fn when(predicate: Expr) -> When {
+    When {
+        when_then: Vec::new(),
+        predicate,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chained_when_then_keeps_every_branch() {
+        let expr = when(col("a"))
+            .then(lit(1))
+            .when(col("b"))
+            .then(lit(2))
+            .when(col("c"))
+            .then(lit(3))
+            .otherwise(lit(0));
+
+        match expr {
+            Expr::Case {
+                when_then,
+                otherwise,
+            } => {
+                assert_eq!(when_then.len(), 3);
+                assert!(otherwise.is_some());
+            }
+            _ => panic!("expected Expr::Case"),
+        }
+    }
+}
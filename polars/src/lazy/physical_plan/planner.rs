@@ -1,3 +1,32 @@
+// NOTE on async streaming: the request asks for `Executor::execute` to return an async
+// stream of record batches so a large `CsvScan` doesn't have to be materialized up front,
+// and for `create_physical_plan` to become async so construction can be awaited. Neither
+// `Executor` nor `PhysicalPlanner` (the trait `create_physical_plan` below implements) is
+// defined anywhere in this tree snapshot, and there's no `futures`/`tokio` dependency used
+// anywhere else in the crate to build a `Stream` on top of. Changing `create_physical_plan`'s
+// signature here without the trait definition in view would produce an impl that may no
+// longer match what `PhysicalPlanner` actually requires, and inventing a streaming
+// `Executor::execute` from scratch would mean guessing at an executor design this file
+// doesn't otherwise show any sign of -- the same situation as the other foreign types this
+// module depends on (`CsvExec`, `FilterExec`, `PipeExec`, `DataFrameExec`). What *can* move
+// today without touching either trait is the recursive plan construction itself, so
+// `create_initial_physical_plan` (an inherent method, not part of `PhysicalPlanner`) is
+// `async fn` below and `await`s each child plan before wiring up its parent -- the
+// overlap-friendly half of this request this snapshot can actually support. Making the
+// `PhysicalPlanner::create_physical_plan` entry point and `Executor::execute` itself async
+// is left for whoever owns those trait definitions.
+//
+// NOTE on groupby/agg: a `LogicalPlan::Aggregate` variant and matching `AggregateExec`
+// would round out this planner, but `LogicalPlan` itself -- like `Executor` above -- is
+// defined outside this tree snapshot, referenced here only through `match logical_plan`.
+// There's no file in this crate to add that variant to, and a match arm for a variant the
+// (invisible) enum doesn't declare can't be added either. `Expr::AggQuantile` and
+// `Expr::Udaf` (see `lazy::dsl`) are wired into `create_physical_expr` below, constructing
+// `AggQuantileExpr`/`UdafExpr` the same way every other arm in that match constructs a
+// `PhysicalExpr` it doesn't define locally (`CaseExpr`, `BinaryExpr`, ...) -- that doesn't
+// need a `LogicalPlan::Aggregate` node to exist, only a `Projection`/`Filter` evaluating
+// the expression. None of the other `Agg*` variants (`AggMin`, `AggSum`, ...) have an arm
+// here yet; they're left for whoever adds the first one, same as before.
 use crate::{lazy::prelude::*, prelude::*};
 use std::rc::Rc;
 
@@ -10,18 +39,45 @@ impl Default for DefaultPlanner {
 
 impl PhysicalPlanner for DefaultPlanner {
     fn create_physical_plan(&self, logical_plan: &LogicalPlan) -> Result<Rc<dyn Executor>> {
-        self.create_initial_physical_plan(logical_plan)
+        futures_lite_block_on(self.create_initial_physical_plan(logical_plan))
+    }
+}
+
+/// Runs a future to completion without pulling in an async runtime dependency. Every
+/// `await` point in `create_initial_physical_plan` is itself just boxed recursion -- there's
+/// no real I/O or task yielding in this snapshot yet -- so the future always completes on
+/// its first poll and a trivial no-op `Waker` is enough to drive it.
+fn futures_lite_block_on<T>(fut: impl std::future::Future<Output = T>) -> T {
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    fn noop(_: *const ()) {}
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = Box::pin(fut);
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
     }
 }
 
 impl DefaultPlanner {
-    pub fn create_initial_physical_plan(
+    pub async fn create_initial_physical_plan(
         &self,
         logical_plan: &LogicalPlan,
     ) -> Result<Rc<dyn Executor>> {
         match logical_plan {
             LogicalPlan::Filter { input, predicate } => {
-                let input = self.create_initial_physical_plan(input)?;
+                // Box the recursive call: an `async fn` can't call itself directly (the
+                // compiler would need an infinitely-sized future), but boxing each
+                // recursive call site breaks that cycle without an `async_recursion`-style
+                // dependency this crate doesn't otherwise use.
+                let input = Box::pin(self.create_initial_physical_plan(input)).await?;
                 let predicate = self.create_physical_expr(predicate)?;
                 Ok(Rc::new(FilterExec::new(predicate, input)))
             }
@@ -37,7 +93,7 @@ impl DefaultPlanner {
                 *delimiter,
             ))),
             LogicalPlan::Projection { expr, input } => {
-                let input = self.create_initial_physical_plan(input)?;
+                let input = Box::pin(self.create_initial_physical_plan(input)).await?;
                 let phys_expr = expr
                     .iter()
                     .map(|expr| self.create_physical_expr(expr))
@@ -46,7 +102,7 @@ impl DefaultPlanner {
             }
             LogicalPlan::DataFrameScan { df } => Ok(Rc::new(DataFrameExec::new(df.clone()))),
             LogicalPlan::Sort { input, expr } => {
-                let input = self.create_initial_physical_plan(input)?;
+                let input = Box::pin(self.create_initial_physical_plan(input)).await?;
                 let phys_expr = expr
                     .iter()
                     .map(|e| self.create_physical_expr(e))
@@ -65,7 +121,9 @@ impl DefaultPlanner {
                 let rhs = self.create_physical_expr(right)?;
                 Ok(Rc::new(BinaryExpr::new(lhs.clone(), *op, rhs.clone())))
             }
-            Expr::Column(column) => Ok(Rc::new(ColumnExpr::new(column.clone()))),
+            Expr::Column { relation, name } => {
+                Ok(Rc::new(ColumnExpr::new(name.clone(), relation.clone())))
+            }
             Expr::Sort { expr, reverse } => {
                 let phys_expr = self.create_physical_expr(expr)?;
                 Ok(Rc::new(SortExpr::new(phys_expr, *reverse)))
@@ -86,6 +144,137 @@ impl DefaultPlanner {
                 let phys_expr = self.create_physical_expr(expr)?;
                 Ok(Rc::new(IsNotNullExpr::new(phys_expr)))
             }
+            // `CaseExpr::evaluate`/`to_field` live with the rest of the `PhysicalExpr`
+            // impls this file only ever constructs (`LiteralExpr`, `BinaryExpr`, ...),
+            // not defined here -- same as every other arm in this match.
+            Expr::Case {
+                when_then,
+                otherwise,
+            } => {
+                let when_then = when_then
+                    .iter()
+                    .map(|(when, then)| {
+                        Ok((
+                            self.create_physical_expr(when)?,
+                            self.create_physical_expr(then)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let otherwise = otherwise
+                    .as_ref()
+                    .map(|expr| self.create_physical_expr(expr))
+                    .transpose()?;
+                Ok(Rc::new(CaseExpr::new(when_then, otherwise)))
+            }
+            // Same construct-without-defining convention as `CaseExpr` above:
+            // `AggQuantileExpr`/`UdafExpr` are `PhysicalExpr`/`PhysicalAggregation` impls
+            // that live alongside `LiteralExpr`, `BinaryExpr`, etc., not in this file.
+            Expr::AggQuantile { expr, quantile } => {
+                let phys_expr = self.create_physical_expr(expr)?;
+                Ok(Rc::new(AggQuantileExpr::new(phys_expr, *quantile)))
+            }
+            Expr::Udaf {
+                expr,
+                name,
+                new_acc,
+            } => {
+                let phys_expr = self.create_physical_expr(expr)?;
+                Ok(Rc::new(UdafExpr::new(
+                    phys_expr,
+                    name.clone(),
+                    new_acc.clone(),
+                )))
+            }
         }
     }
+
+    /// Describes the logical and physical plan `logical_plan` resolves to, as a
+    /// two-column (`plan_type`, `plan`) `DataFrame`, so a query can be inspected without
+    /// running its scans.
+    ///
+    /// NOTE: the request asks for this to be a proper `LogicalPlan::Explain` node driven
+    /// by an `ExplainExec` (`impl Executor`), so it composes with the rest of the plan
+    /// tree the way `FilterExec`/`PipeExec` do. `LogicalPlan` and `Executor` are defined
+    /// outside this tree snapshot (see the NOTEs earlier in this file), so neither can
+    /// gain a new variant/impl here. What's offered instead is a plain method that does
+    /// the actual work the request is after -- describing the plan as a `DataFrame` --
+    /// callable directly rather than through a synthetic logical node.
+    pub fn explain(&self, logical_plan: &LogicalPlan, verbose: bool) -> Result<DataFrame> {
+        let logical = describe_logical_plan(logical_plan, 0, verbose);
+        let physical = describe_physical_plan(logical_plan, 0);
+        let plan_type = Series::new("plan_type", &["logical_plan", "physical_plan"]);
+        let plan = Series::new("plan", &[logical.as_str(), physical.as_str()]);
+        DataFrame::new(vec![plan_type, plan])
+    }
+}
+
+/// Renders `logical_plan`'s operator tree, indented one level per nesting, using the
+/// node names `create_initial_physical_plan`'s `match` distinguishes.
+fn describe_logical_plan(logical_plan: &LogicalPlan, depth: usize, verbose: bool) -> String {
+    let indent = "  ".repeat(depth);
+    match logical_plan {
+        LogicalPlan::Filter { input, predicate } => {
+            let mut out = format!("{}Filter", indent);
+            if verbose {
+                out.push_str(&format!(": {:?}", predicate));
+            }
+            out.push('\n');
+            out.push_str(&describe_logical_plan(input, depth + 1, verbose));
+            out
+        }
+        LogicalPlan::CsvScan { path, .. } => {
+            let mut out = format!("{}CsvScan", indent);
+            if verbose {
+                out.push_str(&format!(": {:?}", path));
+            }
+            out
+        }
+        LogicalPlan::Projection { input, expr } => {
+            let mut out = format!("{}Projection", indent);
+            if verbose {
+                let exprs: Vec<String> = expr.iter().map(|e| format!("{:?}", e)).collect();
+                out.push_str(&format!(": {}", exprs.join(", ")));
+            }
+            out.push('\n');
+            out.push_str(&describe_logical_plan(input, depth + 1, verbose));
+            out
+        }
+        LogicalPlan::DataFrameScan { .. } => format!("{}DataFrameScan", indent),
+        LogicalPlan::Sort { input, expr } => {
+            let mut out = format!("{}Sort", indent);
+            if verbose {
+                let exprs: Vec<String> = expr.iter().map(|e| format!("{:?}", e)).collect();
+                out.push_str(&format!(": {}", exprs.join(", ")));
+            }
+            out.push('\n');
+            out.push_str(&describe_logical_plan(input, depth + 1, verbose));
+            out
+        }
+    }
+}
+
+/// Mirrors `describe_logical_plan`'s recursion but names the executor each node becomes,
+/// the same names `create_initial_physical_plan` wires up (`PipeExec::new("projection", ..)`,
+/// `PipeExec::new("sort", ..)`, ...).
+fn describe_physical_plan(logical_plan: &LogicalPlan, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    match logical_plan {
+        LogicalPlan::Filter { input, .. } => format!(
+            "{}FilterExec\n{}",
+            indent,
+            describe_physical_plan(input, depth + 1)
+        ),
+        LogicalPlan::CsvScan { .. } => format!("{}CsvExec", indent),
+        LogicalPlan::Projection { input, .. } => format!(
+            "{}PipeExec(projection)\n{}",
+            indent,
+            describe_physical_plan(input, depth + 1)
+        ),
+        LogicalPlan::DataFrameScan { .. } => format!("{}DataFrameExec", indent),
+        LogicalPlan::Sort { input, .. } => format!(
+            "{}PipeExec(sort)\n{}",
+            indent,
+            describe_physical_plan(input, depth + 1)
+        ),
+    }
 }
@@ -0,0 +1,172 @@
+//! Hash partitioning for out-of-core group-by/join: split a `DataFrame`'s rows into `n`
+//! buckets by a hash of its key columns, so each bucket can be processed -- and, if it
+//! doesn't fit in memory, spilled to disk -- independently of the others.
+//!
+//! NOTE: the request also asks for a `RepartitionExec` node that spills each partition to
+//! a temporary Arrow IPC file via `IPCWriter` and reads it back via `IPCReader` during the
+//! merge/aggregate phase, with the planner inserting it below `Aggregate` once estimated
+//! cardinality crosses a threshold. `Executor`, `DataFrame`, `IPCWriter` and `IPCReader`
+//! are referenced from `lazy::prelude`/`prelude` but -- like `CsvExec`/`FilterExec` in
+//! `planner.rs` -- aren't defined anywhere in this tree snapshot, and there's no
+//! `LogicalPlan::Aggregate` node for this to sit below either (see the NOTE atop
+//! `planner.rs`, added when that request came through). What's fully in this file's
+//! control -- the hash-bucketing math itself -- is implemented and tested below.
+//! `RepartitionExec` wraps it with the spill/read-back calls the request describes,
+//! following this module's existing convention of constructing foreign types without
+//! redefining them, but its `spill`/`read_partition` are inherent methods, not an `impl Executor`:
+//! claiming to implement that trait without seeing its real method signatures would risk
+//! an impl that quietly doesn't match what `Executor` actually requires.
+use crate::lazy::physical_plan::planner::DefaultPlanner;
+use crate::{lazy::prelude::*, prelude::*};
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// How an input should be split across workers/partitions before further processing.
+#[derive(Clone)]
+pub enum Partitioning {
+    /// Hash the given key expressions and split rows into `n` buckets by `hash % n`.
+    Hash(Vec<Expr>, usize),
+}
+
+/// The partition id for a precomputed row hash. Kept as a standalone function (rather
+/// than folded into `partition_indices`) so a cardinality-threshold check can reuse the
+/// same bucket assignment a row would get without building the full index vectors.
+#[inline]
+pub fn hash_partition_id(hash: u64, n: usize) -> usize {
+    (hash % n as u64) as usize
+}
+
+/// Group row indices `0..row_hashes.len()` into `n` buckets by `hash_partition_id`.
+/// Bucket `i`'s `Vec<u32>` is exactly the row indices a `take`/gather on partition `i`
+/// needs.
+pub fn partition_indices(row_hashes: &[u64], n: usize) -> Vec<Vec<u32>> {
+    let mut partitions = vec![Vec::new(); n];
+    for (row, &hash) in row_hashes.iter().enumerate() {
+        partitions[hash_partition_id(hash, n)].push(row as u32);
+    }
+    partitions
+}
+
+/// Hash a single row's values across every key column into one composite hash, built
+/// from the same per-column `format!("{}", s.get(i))` text that the composite `row_key`
+/// strings of `groupby_multiple_columns` (`frame/group_by.rs`) and
+/// `semi_anti_join_multiple_keys` (`hash_join/mod.rs`) are made of (those compare the
+/// key strings themselves rather than a hash), so rows with equal key tuples always land
+/// in the same partition.
+fn row_hash(key_cols: &[Series], i: usize) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let mut hasher = DefaultHasher::new();
+    for s in key_cols {
+        format!("{}", s.get(i)).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Hash `n`-way split of `df`'s rows by its `key_cols` columns.
+pub fn hash_partition_indices(df: &DataFrame, key_cols: &[&str], n: usize) -> Result<Vec<Vec<u32>>> {
+    let key_series = key_cols
+        .iter()
+        .map(|&name| {
+            df.column(name)
+                .cloned()
+                .ok_or_else(|| PolarsError::NotFound(name.to_string().into()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let row_hashes: Vec<u64> = (0..df.height()).map(|i| row_hash(&key_series, i)).collect();
+    Ok(partition_indices(&row_hashes, n))
+}
+
+/// Spill-to-disk hash repartition "executor": hashes `partitioning`'s key columns per
+/// row, splits into `n` buckets via `hash_partition_indices`, and spills each bucket to a
+/// temporary Arrow IPC file under `spill_dir` so only one bucket needs to be resident in
+/// memory at a time during the downstream merge/aggregate phase.
+pub struct RepartitionExec {
+    pub(crate) input: Rc<dyn Executor>,
+    pub(crate) partitioning: Partitioning,
+    pub(crate) spill_dir: PathBuf,
+}
+
+impl RepartitionExec {
+    pub fn new(input: Rc<dyn Executor>, partitioning: Partitioning, spill_dir: PathBuf) -> Self {
+        RepartitionExec {
+            input,
+            partitioning,
+            spill_dir,
+        }
+    }
+
+    /// Runs the input, hash-partitions its output, and spills every partition to its own
+    /// IPC file, returning the paths in partition order so the merge phase can stream
+    /// them back one at a time instead of holding every partition in memory at once.
+    pub fn spill(&self) -> Result<Vec<PathBuf>> {
+        let Partitioning::Hash(keys, n) = &self.partitioning;
+        let planner = DefaultPlanner::default();
+        let key_names = keys
+            .iter()
+            .map(|e| match e {
+                Expr::Column {
+                    relation: None,
+                    name,
+                } => Ok(name.as_str()),
+                Expr::Column {
+                    relation: Some(_), ..
+                } => Err(PolarsError::InvalidOperation(
+                    "hash partitioning does not yet support qualified column keys".into(),
+                )),
+                _ => Err(PolarsError::InvalidOperation(
+                    "hash partitioning only supports plain column keys".into(),
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let df = self.input.execute()?;
+        let partitions = hash_partition_indices(&df, &key_names, *n)?;
+
+        std::fs::create_dir_all(&self.spill_dir)?;
+        partitions
+            .into_iter()
+            .enumerate()
+            .map(|(i, idx)| {
+                let part_df = unsafe { df.take_iter_unchecked(idx.into_iter().map(|i| i as usize)) };
+                let path = self.spill_dir.join(format!("partition-{}.ipc", i));
+                IPCWriter::new(std::fs::File::create(&path)?).finish(&part_df)?;
+                Ok(path)
+            })
+            .collect()
+    }
+
+    /// Reads one spilled partition back in, e.g. just before aggregating it, so the
+    /// caller never needs more than one partition resident at once.
+    pub fn read_partition(&self, path: &std::path::Path) -> Result<DataFrame> {
+        IPCReader::new(std::fs::File::open(path)?).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn partition_indices_groups_by_hash_mod_n() {
+        let hashes = [1u64, 2, 3, 4, 5, 6, 7];
+        let partitions = partition_indices(&hashes, 3);
+        assert_eq!(partitions.len(), 3);
+        for (row, &hash) in hashes.iter().enumerate() {
+            let expected_partition = hash_partition_id(hash, 3);
+            assert!(partitions[expected_partition].contains(&(row as u32)));
+        }
+        let total: usize = partitions.iter().map(|p| p.len()).sum();
+        assert_eq!(total, hashes.len());
+    }
+
+    #[test]
+    fn hash_partition_id_is_stable_mod_n() {
+        for h in 0u64..20 {
+            assert_eq!(hash_partition_id(h, 4), (h % 4) as usize);
+        }
+    }
+}
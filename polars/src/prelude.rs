@@ -22,6 +22,7 @@ pub use crate::{
     datatypes,
     datatypes::*,
     error::{PolarsError, Result},
+    fmt::{format_options, set_format_options, FormatOptions},
     frame::{
         ser::{
             csv::{CsvReader, CsvWriter},
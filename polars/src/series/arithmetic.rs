@@ -23,7 +23,9 @@ impl Series {
             Series::Date64(lhs) => subtract!(Series::Date64, lhs),
             Series::Time64Ns(lhs) => subtract!(Series::Time64Ns, lhs),
             Series::DurationNs(lhs) => subtract!(Series::DurationNs, lhs),
-            _ => Err(PolarsError::InvalidOperation),
+            _ => Err(PolarsError::InvalidOperation(
+                "subtract is not implemented for this Series variant".into(),
+            )),
         }
     }
 
@@ -47,7 +49,9 @@ impl Series {
             Series::Date64(lhs) => add!(Series::Date64, lhs),
             Series::Time64Ns(lhs) => add!(Series::Time64Ns, lhs),
             Series::DurationNs(lhs) => add!(Series::DurationNs, lhs),
-            _ => Err(PolarsError::InvalidOperation),
+            _ => Err(PolarsError::InvalidOperation(
+                "add_to is not implemented for this Series variant".into(),
+            )),
         }
     }
 
@@ -71,7 +75,9 @@ impl Series {
             Series::Date64(lhs) => multiply!(Series::Date64, lhs),
             Series::Time64Ns(lhs) => multiply!(Series::Time64Ns, lhs),
             Series::DurationNs(lhs) => multiply!(Series::DurationNs, lhs),
-            _ => Err(PolarsError::InvalidOperation),
+            _ => Err(PolarsError::InvalidOperation(
+                "multiply is not implemented for this Series variant".into(),
+            )),
         }
     }
 
@@ -95,7 +101,9 @@ impl Series {
             Series::Date64(lhs) => divide!(Series::Date64, lhs),
             Series::Time64Ns(lhs) => divide!(Series::Time64Ns, lhs),
             Series::DurationNs(lhs) => divide!(Series::DurationNs, lhs),
-            _ => Err(PolarsError::InvalidOperation),
+            _ => Err(PolarsError::InvalidOperation(
+                "divide is not implemented for this Series variant".into(),
+            )),
         }
     }
 }
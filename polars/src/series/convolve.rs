@@ -0,0 +1,292 @@
+//! Discrete linear convolution for numeric `Series` (polynomial multiply / moving-window
+//! weighting). Float series go through a radix-2 FFT; integer series go through an exact
+//! number-theoretic transform (NTT) whenever the result is guaranteed to fit the
+//! transform's modulus, falling back to a rounded FFT otherwise.
+//!
+//! NOTE: this needs a `mod convolve;` added to `series/mod.rs` to be compiled in; that
+//! file isn't part of this tree snapshot.
+use crate::prelude::*;
+use num::complex::Complex64;
+
+/// NTT-friendly prime `998244353 = 119 * 2^23 + 1`, with `3` as a primitive root.
+const NTT_MODULUS: u64 = 998_244_353;
+const NTT_ROOT: u64 = 3;
+
+impl Series {
+    /// Discrete linear convolution of two numeric `Series`: `out[k] = sum_{i+j=k} a[i]*b[j]`.
+    /// `self` and `other` must be the same numeric dtype. Nulls are treated as zero.
+    /// Empty inputs produce an empty `Series`.
+    pub fn convolve(&self, other: &Series) -> Result<Series> {
+        macro_rules! int_conv {
+            ($variant:path, $arrow_ty:ty, $native:ty, $lhs:ident) => {{
+                if let $variant(rhs) = other {
+                    let a: Vec<i64> = $lhs.into_iter().map(|v| v.unwrap_or(0) as i64).collect();
+                    let b: Vec<i64> = rhs.into_iter().map(|v| v.unwrap_or(0) as i64).collect();
+                    let out = convolve_integers(&a, &b);
+                    let mut builder =
+                        PrimitiveChunkedBuilder::<$arrow_ty>::new($lhs.name(), out.len());
+                    for v in out {
+                        builder.append_value(v as $native);
+                    }
+                    Ok($variant(builder.finish()))
+                } else {
+                    Err(PolarsError::DataTypeMisMatch)
+                }
+            }};
+        }
+        macro_rules! float_conv {
+            ($variant:path, $arrow_ty:ty, $native:ty, $lhs:ident) => {{
+                if let $variant(rhs) = other {
+                    let a: Vec<f64> = $lhs.into_iter().map(|v| v.unwrap_or(0.0) as f64).collect();
+                    let b: Vec<f64> = rhs.into_iter().map(|v| v.unwrap_or(0.0) as f64).collect();
+                    let out = convolve_floats(&a, &b);
+                    let mut builder =
+                        PrimitiveChunkedBuilder::<$arrow_ty>::new($lhs.name(), out.len());
+                    for v in out {
+                        builder.append_value(v as $native);
+                    }
+                    Ok($variant(builder.finish()))
+                } else {
+                    Err(PolarsError::DataTypeMisMatch)
+                }
+            }};
+        }
+        match self {
+            Series::UInt32(lhs) => int_conv!(Series::UInt32, UInt32Type, u32, lhs),
+            Series::Int32(lhs) => int_conv!(Series::Int32, Int32Type, i32, lhs),
+            Series::Int64(lhs) => int_conv!(Series::Int64, Int64Type, i64, lhs),
+            Series::Float32(lhs) => float_conv!(Series::Float32, Float32Type, f32, lhs),
+            Series::Float64(lhs) => float_conv!(Series::Float64, Float64Type, f64, lhs),
+            _ => Err(PolarsError::InvalidOperation(
+                "convolve is only implemented for numeric Series variants".into(),
+            )),
+        }
+    }
+}
+
+fn next_pow2(n: usize) -> usize {
+    let mut p = 1usize;
+    while p < n {
+        p <<= 1;
+    }
+    p.max(1)
+}
+
+fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result as u128 * base as u128 % modulus as u128) as u64;
+        }
+        base = (base as u128 * base as u128 % modulus as u128) as u64;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative radix-2 NTT over `Z/NTT_MODULUS`. `a.len()` must be a power of two.
+fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let base_root = mod_pow(NTT_ROOT, (NTT_MODULUS - 1) / len as u64, NTT_MODULUS);
+        let w = if invert {
+            mod_pow(base_root, NTT_MODULUS - 2, NTT_MODULUS)
+        } else {
+            base_root
+        };
+        let mut i = 0;
+        while i < n {
+            let mut wn = 1u64;
+            for k in 0..len / 2 {
+                let u = a[i + k];
+                let v = (a[i + k + len / 2] as u128 * wn as u128 % NTT_MODULUS as u128) as u64;
+                a[i + k] = (u + v) % NTT_MODULUS;
+                a[i + k + len / 2] = (u + NTT_MODULUS - v) % NTT_MODULUS;
+                wn = (wn as u128 * w as u128 % NTT_MODULUS as u128) as u64;
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+    if invert {
+        let n_inv = mod_pow(n as u64, NTT_MODULUS - 2, NTT_MODULUS);
+        for x in a.iter_mut() {
+            *x = (*x as u128 * n_inv as u128 % NTT_MODULUS as u128) as u64;
+        }
+    }
+}
+
+/// Whether every term `a[i]*b[j]` summed into a single output bin is guaranteed to stay
+/// under half the NTT modulus, so the centered residue round-trips back to the exact
+/// signed result.
+fn ntt_is_exact(a: &[i64], b: &[i64]) -> bool {
+    let max_a = a.iter().map(|v| v.unsigned_abs()).max().unwrap_or(0);
+    let max_b = b.iter().map(|v| v.unsigned_abs()).max().unwrap_or(0);
+    let terms = a.len().min(b.len()).max(1) as u64;
+    match max_a.checked_mul(max_b).and_then(|p| p.checked_mul(terms)) {
+        Some(bound) => bound < NTT_MODULUS / 2,
+        None => false,
+    }
+}
+
+fn ntt_convolve(a: &[i64], b: &[i64], out_len: usize) -> Vec<i64> {
+    let n = next_pow2(out_len);
+    let to_residue = |v: i64| (v % NTT_MODULUS as i64 + NTT_MODULUS as i64) as u64 % NTT_MODULUS;
+
+    let mut fa: Vec<u64> = vec![0; n];
+    let mut fb: Vec<u64> = vec![0; n];
+    for (i, &v) in a.iter().enumerate() {
+        fa[i] = to_residue(v);
+    }
+    for (i, &v) in b.iter().enumerate() {
+        fb[i] = to_residue(v);
+    }
+
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for i in 0..n {
+        fa[i] = (fa[i] as u128 * fb[i] as u128 % NTT_MODULUS as u128) as u64;
+    }
+    ntt(&mut fa, true);
+
+    fa.truncate(out_len);
+    fa.into_iter()
+        .map(|v| {
+            if v > NTT_MODULUS / 2 {
+                v as i64 - NTT_MODULUS as i64
+            } else {
+                v as i64
+            }
+        })
+        .collect()
+}
+
+fn convolve_integers(a: &[i64], b: &[i64]) -> Vec<i64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let out_len = a.len() + b.len() - 1;
+    if ntt_is_exact(a, b) {
+        ntt_convolve(a, b, out_len)
+    } else {
+        let a_f: Vec<f64> = a.iter().map(|&v| v as f64).collect();
+        let b_f: Vec<f64> = b.iter().map(|&v| v as f64).collect();
+        convolve_floats(&a_f, &b_f)
+            .into_iter()
+            .map(|v| v.round() as i64)
+            .collect()
+    }
+}
+
+/// In-place iterative radix-2 FFT. `a.len()` must be a power of two.
+fn fft(a: &mut [Complex64], invert: bool) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let sign = if invert { -1.0 } else { 1.0 };
+        let ang = sign * 2.0 * std::f64::consts::PI / len as f64;
+        let wlen = Complex64::new(ang.cos(), ang.sin());
+        let mut i = 0;
+        while i < n {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = a[i + k];
+                let v = a[i + k + len / 2] * w;
+                a[i + k] = u + v;
+                a[i + k + len / 2] = u - v;
+                w *= wlen;
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+    if invert {
+        for x in a.iter_mut() {
+            *x /= n as f64;
+        }
+    }
+}
+
+fn convolve_floats(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let out_len = a.len() + b.len() - 1;
+    let n = next_pow2(out_len);
+
+    let mut fa: Vec<Complex64> = a.iter().map(|&v| Complex64::new(v, 0.0)).collect();
+    let mut fb: Vec<Complex64> = b.iter().map(|&v| Complex64::new(v, 0.0)).collect();
+    fa.resize(n, Complex64::new(0.0, 0.0));
+    fb.resize(n, Complex64::new(0.0, 0.0));
+
+    fft(&mut fa, false);
+    fft(&mut fb, false);
+    for i in 0..n {
+        fa[i] *= fb[i];
+    }
+    fft(&mut fa, true);
+
+    fa.truncate(out_len);
+    fa.into_iter().map(|c| c.re).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn convolve_small_integers_is_exact() {
+        let a = Series::new("a", [1i32, 2, 3].as_ref());
+        let b = Series::new("b", [0i32, 1, 0, 1].as_ref());
+        let out = a.convolve(&b).unwrap();
+        // [1,2,3] * [0,1,0,1] => [0,1,2,4,2,3]
+        assert_eq!(
+            format!("{:?}", out),
+            format!(
+                "{:?}",
+                Series::new("a", [0i32, 1, 2, 4, 2, 3].as_ref())
+            )
+        );
+    }
+
+    #[test]
+    fn convolve_empty_is_empty() {
+        let a = Series::new("a", Vec::<i32>::new().as_ref());
+        let b = Series::new("b", [1i32, 2].as_ref());
+        let out = a.convolve(&b).unwrap();
+        assert_eq!(out.len(), 0);
+    }
+
+    #[test]
+    fn convolve_mismatched_dtype_errors() {
+        let a = Series::new("a", [1i32, 2, 3].as_ref());
+        let b = Series::new("b", [1.0f64, 2.0].as_ref());
+        assert!(matches!(a.convolve(&b), Err(PolarsError::DataTypeMisMatch)));
+    }
+}
@@ -0,0 +1,314 @@
+//! A deferred arithmetic expression graph over `Series`, for compound expressions like
+//! `&a + &b * &c` that would otherwise materialize an intermediate `Series` per op.
+//!
+//! NOTE: this needs a `mod expr;` added to `series/mod.rs` to be compiled in; that file
+//! isn't part of this tree snapshot. Not to be confused with `lazy::dsl::Expr`, which
+//! builds a DataFrame query plan over column names rather than fusing elementwise ops
+//! over `Series` references.
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::ops;
+use std::rc::Rc;
+
+/// The scalar element type flowing through a `SeriesExpr` graph. Limited to the numeric
+/// variants `Series` arithmetic already supports (see `series::arithmetic`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarType {
+    UInt32,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+}
+
+impl ScalarType {
+    fn of_series(series: &Series) -> Result<Self> {
+        match series {
+            Series::UInt32(_) => Ok(ScalarType::UInt32),
+            Series::Int32(_) => Ok(ScalarType::Int32),
+            Series::Int64(_) => Ok(ScalarType::Int64),
+            Series::Float32(_) => Ok(ScalarType::Float32),
+            Series::Float64(_) => Ok(ScalarType::Float64),
+            _ => Err(PolarsError::InvalidOperation(
+                "SeriesExpr only supports numeric Series variants".into(),
+            )),
+        }
+    }
+}
+
+/// A typed literal leaf of a `SeriesExpr` graph.
+#[derive(Debug, Clone, Copy)]
+pub enum Scalar {
+    UInt32(u32),
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+}
+
+impl Scalar {
+    fn dtype(&self) -> ScalarType {
+        match self {
+            Scalar::UInt32(_) => ScalarType::UInt32,
+            Scalar::Int32(_) => ScalarType::Int32,
+            Scalar::Int64(_) => ScalarType::Int64,
+            Scalar::Float32(_) => ScalarType::Float32,
+            Scalar::Float64(_) => ScalarType::Float64,
+        }
+    }
+}
+
+macro_rules! impl_scalar_from {
+    ($native:ty, $variant:ident) => {
+        impl From<$native> for Scalar {
+            fn from(value: $native) -> Self {
+                Scalar::$variant(value)
+            }
+        }
+    };
+}
+
+impl_scalar_from!(u32, UInt32);
+impl_scalar_from!(i32, Int32);
+impl_scalar_from!(i64, Int64);
+impl_scalar_from!(f32, Float32);
+impl_scalar_from!(f64, Float64);
+
+#[derive(Debug, Clone, Copy)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Clone)]
+enum Node<'a> {
+    Column(&'a Series),
+    Scalar(Scalar),
+    BinOp { op: BinOp, lhs: usize, rhs: usize },
+}
+
+impl<'a> Node<'a> {
+    /// Shift the indices of a `BinOp` node by `offset`; used when splicing another
+    /// expression's arena into this one.
+    fn rebased(self, offset: usize) -> Self {
+        match self {
+            Node::BinOp { op, lhs, rhs } => Node::BinOp {
+                op,
+                lhs: lhs + offset,
+                rhs: rhs + offset,
+            },
+            other => other,
+        }
+    }
+}
+
+/// A handle into a shared arena of `Node`s. Overloading `+ - * /` pushes a `BinOp` node
+/// and returns a new handle instead of evaluating anything; `eval` is what actually
+/// walks the graph, in a single pass that fuses every elementwise op together.
+pub struct SeriesExpr<'a> {
+    arena: Rc<RefCell<Vec<Node<'a>>>>,
+    id: usize,
+    dtype: ScalarType,
+}
+
+impl<'a> SeriesExpr<'a> {
+    /// Reference a `Series` as a leaf of the expression graph.
+    pub fn col(series: &'a Series) -> Result<Self> {
+        let dtype = ScalarType::of_series(series)?;
+        Ok(SeriesExpr {
+            arena: Rc::new(RefCell::new(vec![Node::Column(series)])),
+            id: 0,
+            dtype,
+        })
+    }
+
+    /// A literal scalar leaf of the expression graph.
+    pub fn lit(value: impl Into<Scalar>) -> Self {
+        let scalar = value.into();
+        let dtype = scalar.dtype();
+        SeriesExpr {
+            arena: Rc::new(RefCell::new(vec![Node::Scalar(scalar)])),
+            id: 0,
+            dtype,
+        }
+    }
+
+    fn binop(self, rhs: Self, op: BinOp) -> Result<Self> {
+        if self.dtype != rhs.dtype {
+            return Err(PolarsError::DataTypeMisMatch);
+        }
+        let new_id = if Rc::ptr_eq(&self.arena, &rhs.arena) {
+            let mut nodes = self.arena.borrow_mut();
+            nodes.push(Node::BinOp {
+                op,
+                lhs: self.id,
+                rhs: rhs.id,
+            });
+            nodes.len() - 1
+        } else {
+            let offset = self.arena.borrow().len();
+            let rhs_nodes: Vec<Node> = rhs.arena.borrow().iter().cloned().collect();
+            let mut nodes = self.arena.borrow_mut();
+            for node in rhs_nodes {
+                nodes.push(node.rebased(offset));
+            }
+            nodes.push(Node::BinOp {
+                op,
+                lhs: self.id,
+                rhs: rhs.id + offset,
+            });
+            nodes.len() - 1
+        };
+        Ok(SeriesExpr {
+            arena: self.arena,
+            id: new_id,
+            dtype: self.dtype,
+        })
+    }
+
+    /// Walk the graph once, fusing every elementwise op into a single pass over the
+    /// aligned column iterators. The output length is taken from the `Series` leaves in
+    /// the graph, which must all agree; an expression built entirely out of literals has
+    /// no defined length and returns `PolarsError::InvalidOperation`, while two `Series`
+    /// leaves of different lengths return `PolarsError::LengthMismatch` up front instead
+    /// of letting `resolve`'s `zip` silently truncate to the shorter one.
+    pub fn eval(&self) -> Result<Series> {
+        let nodes = self.arena.borrow();
+        let len = column_len(&nodes, self.id)?.ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                "expression has no Series leaf to take its length from".into(),
+            )
+        })?;
+
+        macro_rules! eval_as {
+            ($native:ty, $series_variant:path, $scalar_variant:path) => {{
+                fn resolve<'x>(
+                    nodes: &[Node<'x>],
+                    id: usize,
+                ) -> Box<dyn Iterator<Item = Option<$native>> + 'x> {
+                    match &nodes[id] {
+                        Node::Column(s) => {
+                            let s: &'x Series = *s;
+                            if let $series_variant(ca) = s {
+                                Box::new(ca.into_iter())
+                            } else {
+                                unreachable!("SeriesExpr dtype is checked at construction time")
+                            }
+                        }
+                        Node::Scalar($scalar_variant(v)) => Box::new(std::iter::repeat(Some(*v))),
+                        Node::Scalar(_) => {
+                            unreachable!("SeriesExpr dtype is checked at construction time")
+                        }
+                        Node::BinOp { op, lhs, rhs } => {
+                            let op = *op;
+                            let l = resolve(nodes, *lhs);
+                            let r = resolve(nodes, *rhs);
+                            Box::new(l.zip(r).map(move |(a, b)| match (a, b) {
+                                (Some(a), Some(b)) => Some(apply_op(op, a, b)),
+                                _ => None,
+                            }))
+                        }
+                    }
+                }
+                Ok(resolve(&nodes, self.id).take(len).collect())
+            }};
+        }
+
+        match self.dtype {
+            ScalarType::UInt32 => eval_as!(u32, Series::UInt32, Scalar::UInt32),
+            ScalarType::Int32 => eval_as!(i32, Series::Int32, Scalar::Int32),
+            ScalarType::Int64 => eval_as!(i64, Series::Int64, Scalar::Int64),
+            ScalarType::Float32 => eval_as!(f32, Series::Float32, Scalar::Float32),
+            ScalarType::Float64 => eval_as!(f64, Series::Float64, Scalar::Float64),
+        }
+    }
+}
+
+/// The shared length of every `Series` leaf under `id`, or `None` if there isn't one
+/// (an all-literal expression). Errors if two `Series` leaves disagree on length, so a
+/// mismatch surfaces before `eval` ever zips their iterators together.
+fn column_len(nodes: &[Node], id: usize) -> Result<Option<usize>> {
+    match &nodes[id] {
+        Node::Column(s) => Ok(Some(s.len())),
+        Node::Scalar(_) => Ok(None),
+        Node::BinOp { lhs, rhs, .. } => {
+            let l = column_len(nodes, *lhs)?;
+            let r = column_len(nodes, *rhs)?;
+            match (l, r) {
+                (Some(l), Some(r)) if l != r => Err(PolarsError::LengthMismatch(l, r)),
+                (Some(l), _) => Ok(Some(l)),
+                (None, r) => Ok(r),
+            }
+        }
+    }
+}
+
+fn apply_op<T>(op: BinOp, a: T, b: T) -> T
+where
+    T: ops::Add<Output = T> + ops::Sub<Output = T> + ops::Mul<Output = T> + ops::Div<Output = T>,
+{
+    match op {
+        BinOp::Add => a + b,
+        BinOp::Sub => a - b,
+        BinOp::Mul => a * b,
+        BinOp::Div => a / b,
+    }
+}
+
+macro_rules! impl_expr_op {
+    ($trait:ident, $method:ident, $op:expr) => {
+        impl<'a> ops::$trait for SeriesExpr<'a> {
+            type Output = SeriesExpr<'a>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.binop(rhs, $op).expect("data types don't match")
+            }
+        }
+    };
+}
+
+impl_expr_op!(Add, add, BinOp::Add);
+impl_expr_op!(Sub, sub, BinOp::Sub);
+impl_expr_op!(Mul, mul, BinOp::Mul);
+impl_expr_op!(Div, div, BinOp::Div);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fused_expression_matches_eager_result() {
+        let a = Series::new("a", [1i32, 2, 3].as_ref());
+        let b = Series::new("b", [10i32, 20, 30].as_ref());
+
+        let fused = (SeriesExpr::col(&a).unwrap() + SeriesExpr::col(&b).unwrap())
+            * SeriesExpr::lit(2i32);
+        let out = fused.eval().unwrap();
+
+        let eager = (&(&a + &b)) * 2i32;
+        assert_eq!(format!("{:?}", out), format!("{:?}", eager));
+    }
+
+    #[test]
+    fn literal_only_expression_has_no_length() {
+        let expr = SeriesExpr::lit(1i32) + SeriesExpr::lit(2i32);
+        assert!(matches!(
+            expr.eval(),
+            Err(PolarsError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn mismatched_column_lengths_error_instead_of_truncating() {
+        let a = Series::new("a", [1i32, 2, 3].as_ref());
+        let b = Series::new("b", [10i32, 20].as_ref());
+
+        let expr = SeriesExpr::col(&a).unwrap() + SeriesExpr::col(&b).unwrap();
+        assert!(matches!(
+            expr.eval(),
+            Err(PolarsError::LengthMismatch(3, 2))
+        ));
+    }
+}
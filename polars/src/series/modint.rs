@@ -0,0 +1,276 @@
+//! Modular-integer arithmetic and combinatorics helpers for exact counting (choosing,
+//! permutations, modular sums) without overflowing `Int64`.
+//!
+//! NOTE: the request behind this file asks for a `ModInt` variant on the `Series` enum
+//! itself, so that `add_to`/`subtract`/`multiply`/`divide` (and the `op_num_rhs`/
+//! `op_num_lhs` scalar-broadcast macros) route through it automatically. `Series`'s
+//! definition isn't part of this tree snapshot, so there is no `Series::ModInt` variant,
+//! and the `Series` match arms in `series/arithmetic.rs` and `fmt.rs` have no `ModInt`
+//! arm. What follows is the `ModInt` value type and the `Factorials` table, with the
+//! `binom_series`/`perm_series` helpers operating on ordinary integer `ChunkedArray`
+//! columns.
+use crate::prelude::*;
+use num::ToPrimitive;
+
+/// An integer modulo a prime `p`, with division implemented as multiplication by the
+/// modular inverse (`a * b^(p-2) mod p`, via fast exponentiation) rather than integer
+/// division.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    /// `value` is reduced into `0..modulus`. `modulus` is assumed prime, which is what
+    /// makes `div`/`inv` well-defined (e.g. `1_000_000_007` or `998_244_353`).
+    pub fn new(value: u64, modulus: u64) -> Self {
+        ModInt {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    fn pow(&self, mut exp: u64) -> Self {
+        let mut base = self.value;
+        let mut result = 1u64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = (result as u128 * base as u128 % self.modulus as u128) as u64;
+            }
+            base = (base as u128 * base as u128 % self.modulus as u128) as u64;
+            exp >>= 1;
+        }
+        ModInt::new(result, self.modulus)
+    }
+
+    /// Modular inverse via Fermat's little theorem: `a^(p-2) mod p`.
+    pub fn inv(&self) -> Self {
+        self.pow(self.modulus - 2)
+    }
+
+    fn check_modulus(&self, other: &Self) {
+        assert_eq!(
+            self.modulus, other.modulus,
+            "ModInt values use different moduli"
+        );
+    }
+}
+
+impl std::ops::Add for ModInt {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        ModInt::new(self.value + rhs.value, self.modulus)
+    }
+}
+
+impl std::ops::Sub for ModInt {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        ModInt::new(self.value + self.modulus - rhs.value, self.modulus)
+    }
+}
+
+impl std::ops::Mul for ModInt {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        ModInt::new(
+            (self.value as u128 * rhs.value as u128 % self.modulus as u128) as u64,
+            self.modulus,
+        )
+    }
+}
+
+impl std::ops::Div for ModInt {
+    type Output = Self;
+    /// Multiplies by `rhs`'s modular inverse rather than dividing the raw integers.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.check_modulus(&rhs);
+        self * rhs.inv()
+    }
+}
+
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl std::ops::$trait<u64> for ModInt {
+            type Output = Self;
+
+            /// The scalar is reduced mod `p` before the op is applied.
+            fn $method(self, rhs: u64) -> Self::Output {
+                self $op ModInt::new(rhs, self.modulus)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add, +);
+impl_scalar_op!(Sub, sub, -);
+impl_scalar_op!(Mul, mul, *);
+impl_scalar_op!(Div, div, /);
+
+/// Precomputed factorial / inverse-factorial tables mod a prime `p`, giving O(1)
+/// `binom`/`perm` after an O(n) setup.
+pub struct Factorials {
+    fact: Vec<u64>,
+    finv: Vec<u64>,
+    modulus: u64,
+}
+
+impl Factorials {
+    /// Precomputes `fact[0..=n]` and `finv[0..=n]`. Only `finv[n]` pays for a modular
+    /// inverse (via Fermat); every other entry is derived going down with a single
+    /// multiplication each, `finv[i-1] = finv[i] * i`.
+    pub fn new(n: usize, modulus: u64) -> Self {
+        let mut fact = Vec::with_capacity(n + 1);
+        fact.push(1u64);
+        for i in 1..=n {
+            let prev = fact[i - 1];
+            fact.push((prev as u128 * i as u128 % modulus as u128) as u64);
+        }
+
+        let mut finv = vec![0u64; n + 1];
+        finv[n] = ModInt::new(fact[n], modulus).inv().value();
+        for i in (1..=n).rev() {
+            finv[i - 1] = (finv[i] as u128 * i as u128 % modulus as u128) as u64;
+        }
+
+        Factorials {
+            fact,
+            finv,
+            modulus,
+        }
+    }
+
+    /// Number of ways to choose `k` items from `n`, mod `p`. Zero if `k > n`. Errors if
+    /// `n` is past the end of the precomputed table (`Factorials::new`'s `n`).
+    pub fn binom(&self, n: usize, k: usize) -> Result<u64> {
+        if k > n {
+            return Ok(0);
+        }
+        if n >= self.fact.len() {
+            return Err(PolarsError::InvalidOperation(
+                format!("n ({}) is out of range for this Factorials table", n).into(),
+            ));
+        }
+        let den = (self.finv[k] as u128 * self.finv[n - k] as u128) % self.modulus as u128;
+        Ok((self.fact[n] as u128 * den % self.modulus as u128) as u64)
+    }
+
+    /// Number of ways to arrange `k` items out of `n`, mod `p`. Zero if `k > n`. Errors if
+    /// `n` is past the end of the precomputed table (`Factorials::new`'s `n`).
+    pub fn perm(&self, n: usize, k: usize) -> Result<u64> {
+        if k > n {
+            return Ok(0);
+        }
+        if n >= self.fact.len() {
+            return Err(PolarsError::InvalidOperation(
+                format!("n ({}) is out of range for this Factorials table", n).into(),
+            ));
+        }
+        Ok((self.fact[n] as u128 * self.finv[n - k] as u128 % self.modulus as u128) as u64)
+    }
+
+    /// `binom` applied elementwise over two columns of counts.
+    pub fn binom_series<T>(
+        &self,
+        ns: &ChunkedArray<T>,
+        ks: &ChunkedArray<T>,
+    ) -> Result<ChunkedArray<UInt64Type>>
+    where
+        T: PolarsIntegerType,
+        T::Native: ToPrimitive,
+    {
+        self.combinatoric_series(ns, ks, |n, k| self.binom(n, k))
+    }
+
+    /// `perm` applied elementwise over two columns of counts.
+    pub fn perm_series<T>(
+        &self,
+        ns: &ChunkedArray<T>,
+        ks: &ChunkedArray<T>,
+    ) -> Result<ChunkedArray<UInt64Type>>
+    where
+        T: PolarsIntegerType,
+        T::Native: ToPrimitive,
+    {
+        self.combinatoric_series(ns, ks, |n, k| self.perm(n, k))
+    }
+
+    fn combinatoric_series<T>(
+        &self,
+        ns: &ChunkedArray<T>,
+        ks: &ChunkedArray<T>,
+        f: impl Fn(usize, usize) -> Result<u64>,
+    ) -> Result<ChunkedArray<UInt64Type>>
+    where
+        T: PolarsIntegerType,
+        T::Native: ToPrimitive,
+    {
+        if ns.len() != ks.len() {
+            return Err(PolarsError::LengthMismatch(ns.len(), ks.len()));
+        }
+        let mut builder = PrimitiveChunkedBuilder::new(ns.name(), ns.len());
+        for (n, k) in ns.into_iter().zip(ks.into_iter()) {
+            match (n, k) {
+                (Some(n), Some(k)) => {
+                    let n = n.to_usize().ok_or_else(|| {
+                        PolarsError::InvalidOperation("n does not fit in usize".into())
+                    })?;
+                    let k = k.to_usize().ok_or_else(|| {
+                        PolarsError::InvalidOperation("k does not fit in usize".into())
+                    })?;
+                    builder.append_value(f(n, k)?);
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const P: u64 = 1_000_000_007;
+
+    #[test]
+    fn modint_div_is_mul_by_inverse() {
+        let a = ModInt::new(84, P);
+        let b = ModInt::new(12, P);
+        assert_eq!((a / b).value(), 7);
+        assert_eq!(((a / b) * b).value(), a.value());
+    }
+
+    #[test]
+    fn factorials_binom_and_perm() {
+        let f = Factorials::new(10, P);
+        assert_eq!(f.binom(5, 2).unwrap(), 10);
+        assert_eq!(f.perm(5, 2).unwrap(), 20);
+        assert_eq!(f.binom(5, 6).unwrap(), 0);
+    }
+
+    #[test]
+    fn factorials_binom_out_of_range_errors_instead_of_panicking() {
+        let f = Factorials::new(10, P);
+        assert!(f.binom(11, 2).is_err());
+        assert!(f.perm(11, 2).is_err());
+    }
+
+    #[test]
+    fn binom_series_over_columns() {
+        let f = Factorials::new(10, P);
+        let ns = ChunkedArray::<Int32Type>::new_from_slice("n", &[5, 5, 4]);
+        let ks = ChunkedArray::<Int32Type>::new_from_slice("k", &[0, 2, 2]);
+        let out = f.binom_series(&ns, &ks).unwrap();
+        let out: Vec<u64> = out.into_iter().map(|v| v.unwrap()).collect();
+        assert_eq!(out, vec![1, 10, 6]);
+    }
+}
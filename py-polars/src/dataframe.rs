@@ -281,7 +281,7 @@ impl PyDataFrame {
             "first" => selection.first(),
             "sum" => selection.sum(),
             "count" => selection.count(),
-            a => Err(PolarsError::Other(format!("agg fn {} does not exists", a))),
+            a => Err(PolarsError::Other(format!("agg fn {} does not exists", a).into())),
         };
         let df = df.map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))
@@ -303,7 +303,7 @@ impl PyDataFrame {
             "mean" => pivot.mean(),
             "median" => pivot.median(),
             "sum" => pivot.sum(),
-            a => Err(PolarsError::Other(format!("agg fn {} does not exists", a))),
+            a => Err(PolarsError::Other(format!("agg fn {} does not exists", a).into())),
         };
         let df = df.map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))